@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+use crate::resources::boss::BossAttack;
+use crate::systems::world_generation::WorldZone;
+
+// 보스 전투 상태 - EnemyType::Boss 로 생성된 적에 붙음 (BossDatabase 키)
+#[derive(Component)]
+pub struct BossState {
+    pub boss_id: String,
+    pub active: bool,       // 플레이어가 아레나에 들어와 전투 중
+    pub phase: usize,       // BossDefinition::phases 인덱스
+    pub attack_cooldowns: HashMap<String, f32>,
+    pub current_attack: Option<BossAttack>,  // 예비 동작 중인 공격
+    pub action_timer: f32,  // 예비 동작 / 공격 후 빈틈 / 페이즈 전환 남은 시간
+    pub home: Vec3,         // 플레이어가 죽으면 돌아갈 위치
+}
+
+impl BossState {
+    pub fn new(boss_id: &str, home: Vec3) -> Self {
+        Self {
+            boss_id: boss_id.to_string(),
+            active: false,
+            phase: 0,
+            attack_cooldowns: HashMap::new(),
+            current_attack: None,
+            action_timer: 0.0,
+            home,
+        }
+    }
+
+    // 처음 상태로 - 플레이어가 죽거나 체크포인트에서 쉬면 전투가 초기화됨
+    pub fn reset(&mut self) {
+        self.active = false;
+        self.phase = 0;
+        self.attack_cooldowns.clear();
+        self.current_attack = None;
+        self.action_timer = 0.0;
+    }
+}
+
+// 보스 아레나 - 플레이어가 안에 들어오면 안개벽으로 봉인, 보스를 쓰러뜨리면 열림
+#[derive(Component)]
+pub struct BossArena {
+    pub boss_id: String,
+    pub radius: f32,
+    pub zone: WorldZone,
+    pub sealed: bool,
+}
+
+// 아레나 가장자리 안개벽 조각 - 봉인 중에만 보이고 막힘
+#[derive(Component)]
+pub struct FogWall {
+    pub boss_id: String,
+}
+
+// 보스가 해당 페이즈에 들어서면 나타나는 아레나 지형 (부유 발판, 솟아오른 결정 등)
+#[derive(Component)]
+pub struct ArenaPhaseElement {
+    pub boss_id: String,
+    pub phase: usize,
+}
+
+// 보스전 시작 - 음악, 연출 등이 반응
+#[derive(Event)]
+pub struct BossEncounterStartedEvent {
+    pub boss_id: String,
+}
+
+// 보스 페이즈 전환 - 아레나 지형, 음악 등이 반응
+#[derive(Event)]
+pub struct BossPhaseChangedEvent {
+    pub boss_id: String,
+    pub phase: usize,
+}
+
+// 보스 처치 - 처치 배너 등이 반응
+#[derive(Event)]
+pub struct BossDefeatedEvent {
+    pub boss_id: String,
+}
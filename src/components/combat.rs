@@ -1,46 +1,243 @@
 use bevy::prelude::*;
+use bevy::utils::HashSet;
+use crate::components::player::AnimationState;
+use crate::components::damage::{DamagePacket, DamageType, StatusType};
 
 #[derive(Component)]
 pub struct Weapon {
     pub damage: f32,
+    pub damage_type: DamageType,                  // 기본 데미지의 속성
+    pub elemental_damage: Vec<(DamageType, f32)>, // 추가 속성 데미지 (화염, 마법, 결정 ...)
+    pub status_buildup: Vec<(StatusType, f32)>,   // 타격당 상태이상 축적치
     pub attack_range: f32,
     pub attack_cooldown: f32,
     pub stamina_cost: f32,
+    pub moveset_id: String,  // MovesetDatabase 키 (없으면 단일 공격)
+    pub guard_absorption: f32,  // 가드 시 흡수하는 피해 비율 (0.0 ~ 1.0)
+    pub guard_stability: f32,   // 가드 시 스테미나 소모 감소 비율 (0.0 ~ 1.0)
+    pub critical_multiplier: f32,  // 앞잡기/뒤잡기 데미지 배율
+    pub strength_scaling: f32,     // 근력 보정 계수
+    pub dexterity_scaling: f32,    // 기량 보정 계수
+    pub attribute_bonus: f32,      // 능력치 보정으로 더해지는 기본 데미지 (CharacterStats 로 계산)
+    pub animation_set: String,     // animation_mapping.json 의 세트 이름 (Aurora, GreatSword ...)
+    pub upgrade_level: u32,        // 강화 단계 (+0 ~ +10)
+    pub durability: f32,
+    pub max_durability: f32,       // 0 이면 부서지지 않음 (맨손)
 }
 
 impl Default for Weapon {
     fn default() -> Self {
         Self {
             damage: 25.0,
+            damage_type: DamageType::Slash,
+            elemental_damage: Vec::new(),
+            status_buildup: Vec::new(),
             attack_range: 2.0,
             attack_cooldown: 1.0,
             stamina_cost: 15.0,
+            moveset_id: "straight_sword".to_string(),
+            guard_absorption: 0.6,
+            guard_stability: 0.25,
+            critical_multiplier: 3.0,
+            strength_scaling: 0.4,
+            dexterity_scaling: 0.6,
+            attribute_bonus: 0.0,
+            animation_set: "Aurora".to_string(),
+            upgrade_level: 0,
+            durability: 100.0,
+            max_durability: 100.0,
         }
     }
 }
 
-#[derive(Component, Default)]
+// 내구도가 다 닳은 무기의 데미지 배율
+pub const BROKEN_WEAPON_DAMAGE_SCALE: f32 = 0.3;
+
+impl Weapon {
+    pub fn is_broken(&self) -> bool {
+        self.max_durability > 0.0 && self.durability <= 0.0
+    }
+
+    // 모션 배율을 적용한 한 번의 공격 데미지 (부서진 무기는 크게 감소)
+    pub fn damage_packet(&self, multiplier: f32) -> DamagePacket {
+        let multiplier = if self.is_broken() {
+            multiplier * BROKEN_WEAPON_DAMAGE_SCALE
+        } else {
+            multiplier
+        };
+
+        let mut amounts = vec![(self.damage_type, self.damage + self.attribute_bonus)];
+        amounts.extend(self.elemental_damage.iter().copied());
+
+        DamagePacket {
+            amounts,
+            status_buildup: self.status_buildup.clone(),
+        }
+        .scaled(multiplier)
+    }
+}
+
+#[derive(Component)]
 pub struct AttackState {
     pub is_attacking: bool,
     pub attack_timer: f32,
     pub can_attack: bool,
+    pub combo_index: usize,              // 다음에 나갈 약공격 단계 (0 = A)
+    pub combo_window_timer: f32,         // 0보다 크면 다음 입력이 콤보로 이어짐
+    pub queued_attack: bool,             // 동작 중 선입력
+    pub is_charging: bool,               // 강공격 차지 중
+    pub charge_timer: f32,
+    pub current_animation: AnimationState,
+}
+
+impl Default for AttackState {
+    fn default() -> Self {
+        Self {
+            is_attacking: false,
+            attack_timer: 0.0,
+            can_attack: true,
+            combo_index: 0,
+            combo_window_timer: 0.0,
+            queued_attack: false,
+            is_charging: false,
+            charge_timer: 0.0,
+            current_animation: AnimationState::Slash,
+        }
+    }
+}
+
+#[derive(Component, Default)]
+pub struct GuardState {
+    pub is_guarding: bool,
+    pub guard_break_timer: f32,  // 0보다 크면 가드 브레이크 경직 중
+}
+
+impl GuardState {
+    pub fn is_guard_broken(&self) -> bool {
+        self.guard_break_timer > 0.0
+    }
+}
+
+#[derive(Component)]
+pub struct ParryState {
+    pub timer: f32,          // 패링 동작 남은 시간
+    pub duration: f32,       // 패링 동작 전체 시간
+    pub active_window: f32,  // 동작 시작부터 공격을 흘려내는 시간
+    pub stun_duration: f32,  // 패링당한 적의 경직 시간
+    pub stamina_cost: f32,
+}
+
+impl Default for ParryState {
+    fn default() -> Self {
+        Self {
+            timer: 0.0,
+            duration: 0.5,
+            active_window: 0.2,
+            stun_duration: 2.5,
+            stamina_cost: 10.0,
+        }
+    }
+}
+
+impl ParryState {
+    pub fn is_parrying(&self) -> bool {
+        self.timer > 0.0
+    }
+
+    pub fn is_window_active(&self) -> bool {
+        self.is_parrying() && self.duration - self.timer <= self.active_window
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CriticalKind {
+    Riposte,   // 경직(Stunned)된 적 앞잡기
+    Backstab,  // 눈치채지 못한 적 뒤잡기
+}
+
+#[derive(Component)]
+pub struct CriticalAttackState {
+    pub timer: f32,                 // 치명타 연출 남은 시간
+    pub target: Option<Entity>,
+    pub kind: CriticalKind,
+    pub duration: f32,              // 연출 전체 시간 (이동/입력 잠금)
+    pub riposte_distance: f32,
+    pub backstab_distance: f32,
+    pub backstab_angle: f32,        // 적 등 뒤 기준 허용 각도 (라디안)
+    pub facing_angle: f32,          // 플레이어가 적을 바라봐야 하는 허용 각도 (라디안)
+}
+
+impl Default for CriticalAttackState {
+    fn default() -> Self {
+        Self {
+            timer: 0.0,
+            target: None,
+            kind: CriticalKind::Riposte,
+            duration: 1.6,
+            riposte_distance: 2.2,
+            backstab_distance: 1.6,
+            backstab_angle: std::f32::consts::FRAC_PI_4,
+            facing_angle: std::f32::consts::FRAC_PI_3,
+        }
+    }
+}
+
+impl CriticalAttackState {
+    pub fn is_active(&self) -> bool {
+        self.timer > 0.0
+    }
 }
 
 #[derive(Component)]
 pub struct Hitbox {
+    pub owner: Entity,
     pub radius: f32,
-    pub damage: f32,
+    pub damage: DamagePacket,
+    pub poise_damage: f32,
     pub active: bool,
     pub lifetime: f32,
+    pub offset: Vec3,                  // 소유자 로컬 좌표 기준 위치
+    pub hit_entities: HashSet<Entity>, // 이번 공격에서 이미 맞은 대상
 }
 
 impl Default for Hitbox {
     fn default() -> Self {
         Self {
+            owner: Entity::PLACEHOLDER,
             radius: 1.5,
-            damage: 25.0,
+            damage: DamagePacket::single(DamageType::Physical, 25.0),
+            poise_damage: 10.0,
             active: false,
             lifetime: 0.2,
+            offset: Vec3::ZERO,
+            hit_entities: HashSet::new(),
+        }
+    }
+}
+// 날아가는 투사체 (화살 등) - 적 궁수, 함정, 플레이어 원거리 무기가 공용으로 사용
+#[derive(Component)]
+pub struct Projectile {
+    pub owner: Entity,
+    pub damage: DamagePacket,
+    pub poise_damage: f32,
+    pub gravity_scale: f32,
+    pub lifetime: f32,
+    pub stick_on_impact: bool,  // 지형에 닿으면 박혀서 남음 (false 면 바로 제거)
+    pub stuck: bool,
+    pub previous_position: Vec3,  // 지난 프레임 위치 - 빠른 투사체가 얇은 벽을 뚫지 않도록 이 구간을 검사
+}
+
+impl Default for Projectile {
+    fn default() -> Self {
+        Self {
+            owner: Entity::PLACEHOLDER,
+            damage: DamagePacket::single(DamageType::Thrust, 15.0),
+            poise_damage: 5.0,
+            gravity_scale: 1.0,
+            lifetime: 5.0,
+            stick_on_impact: true,
+            stuck: false,
+            previous_position: Vec3::ZERO,
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,210 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// 데미지 속성 - Slash/Strike/Thrust 는 물리의 세부 속성
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DamageType {
+    Physical,
+    Slash,
+    Strike,
+    Thrust,
+    Fire,
+    Magic,
+    Crystal,  // Z2 결정 숲 계열
+}
+
+impl DamageType {
+    pub fn is_physical(&self) -> bool {
+        matches!(self, DamageType::Physical | DamageType::Slash | DamageType::Strike | DamageType::Thrust)
+    }
+}
+
+// 축적형 상태이상
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum StatusType {
+    Bleed,          // 발동 시 최대 체력 비례 즉시 피해
+    Poison,         // 발동 후 지속 피해
+    GravityBlight,  // 발동 시 피해 + 받는 피해 증가, 이동 둔화
+}
+
+impl StatusType {
+    // 저항 데이터가 없을 때의 발동 임계치
+    pub fn base_threshold(&self) -> f32 {
+        match self {
+            StatusType::Bleed => 100.0,
+            StatusType::Poison => 120.0,
+            StatusType::GravityBlight => 110.0,
+        }
+    }
+
+    // 발동 순간 최대 체력 대비 피해 비율
+    pub fn burst_damage_ratio(&self) -> f32 {
+        match self {
+            StatusType::Bleed => 0.15,
+            StatusType::Poison => 0.0,
+            StatusType::GravityBlight => 0.1,
+        }
+    }
+
+    // 발동 후 효과 지속 시간 (0이면 즉발)
+    pub fn effect_duration(&self) -> f32 {
+        match self {
+            StatusType::Bleed => 0.0,
+            StatusType::Poison => 20.0,
+            StatusType::GravityBlight => 15.0,
+        }
+    }
+
+    // 효과 지속 중 초당 최대 체력 대비 피해 비율
+    pub fn tick_damage_ratio(&self) -> f32 {
+        match self {
+            StatusType::Poison => 0.01,
+            _ => 0.0,
+        }
+    }
+}
+
+// 한 번의 공격에 실린 속성별 데미지와 상태이상 축적치
+#[derive(Clone, Debug, Default)]
+pub struct DamagePacket {
+    pub amounts: Vec<(DamageType, f32)>,
+    pub status_buildup: Vec<(StatusType, f32)>,
+}
+
+impl DamagePacket {
+    pub fn single(damage_type: DamageType, amount: f32) -> Self {
+        Self {
+            amounts: vec![(damage_type, amount)],
+            status_buildup: Vec::new(),
+        }
+    }
+
+    // 데미지만 배율 적용 (상태이상 축적치는 모션과 무관)
+    pub fn scaled(&self, multiplier: f32) -> Self {
+        Self {
+            amounts: self.amounts.iter().map(|(t, a)| (*t, a * multiplier)).collect(),
+            status_buildup: self.status_buildup.clone(),
+        }
+    }
+
+    pub fn total(&self) -> f32 {
+        self.amounts.iter().map(|(_, a)| a).sum()
+    }
+}
+
+// 피해의 출처 - 출처에 따라 무적/패링/가드 판정 여부가 달라짐
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DamageSource {
+    Melee,         // 일반 공격 - 모든 판정 적용
+    Critical,      // 앞잡기/뒤잡기 - 무적/패링/가드 무시
+    Projectile,    // 화살 등 - 무적/가드 적용, 패링 불가
+    Area,          // 광역 충격파 - 무적만 적용, 패링/가드 불가
+    StatusEffect,  // 상태이상 지속 피해 - 저항/강인도 무시
+}
+
+// 피해 요청 - damage_resolution_system 에서 한 곳에서 처리
+#[derive(Event, Clone)]
+pub struct DamageEvent {
+    pub attacker: Entity,
+    pub victim: Entity,
+    pub damage: DamagePacket,
+    pub poise_damage: f32,
+    pub hit_position: Vec3,
+    pub source: DamageSource,
+}
+
+// 체력이 0이 된 순간 한 번 발생
+#[derive(Event, Clone)]
+pub struct DeathEvent {
+    pub entity: Entity,
+    pub killer: Option<Entity>,
+    pub position: Vec3,
+}
+
+// 쓰러진 적 - 연출이 끝나면 제거
+#[derive(Component)]
+pub struct Dead {
+    pub despawn_timer: f32,
+}
+
+// 속성별 데미지 감소율 (음수면 약점) 과 상태이상 임계치
+#[derive(Component, Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Resistances {
+    #[serde(default)]
+    pub damage: HashMap<DamageType, f32>,
+    #[serde(default)]
+    pub status: HashMap<StatusType, f32>,
+}
+
+impl Resistances {
+    // 물리 세부 속성은 물리 감소율도 함께 적용
+    pub fn damage_resistance(&self, damage_type: DamageType) -> f32 {
+        let mut resistance = self.damage.get(&damage_type).copied().unwrap_or(0.0);
+        if damage_type.is_physical() && damage_type != DamageType::Physical {
+            resistance += self.damage.get(&DamageType::Physical).copied().unwrap_or(0.0);
+        }
+        resistance.min(0.9)
+    }
+
+    pub fn status_threshold(&self, status_type: StatusType) -> f32 {
+        self.status
+            .get(&status_type)
+            .copied()
+            .unwrap_or_else(|| status_type.base_threshold())
+    }
+
+    // 감소율 적용 후 총 데미지
+    pub fn mitigate(&self, packet: &DamagePacket) -> f32 {
+        packet
+            .amounts
+            .iter()
+            .map(|(t, a)| a * (1.0 - self.damage_resistance(*t)))
+            .sum::<f32>()
+            .max(0.0)
+    }
+}
+
+// 상태이상 축적치와 발동 중인 효과
+#[derive(Component, Default)]
+pub struct StatusBuildup {
+    pub buildup: HashMap<StatusType, f32>,
+    pub active: HashMap<StatusType, f32>,  // 효과의 남은 시간
+    pub last_attacker: Option<Entity>,     // 마지막으로 상태이상을 쌓은 쪽 - 지속 피해로 쓰러지면 처치자로 처리
+}
+
+impl StatusBuildup {
+    // 축적치 추가 (scale 배) - 임계치를 넘어 발동한 상태이상 반환
+    pub fn accumulate(&mut self, packet: &DamagePacket, resistances: &Resistances, scale: f32) -> Vec<StatusType> {
+        let mut triggered = Vec::new();
+
+        for (status_type, amount) in &packet.status_buildup {
+            let value = self.buildup.entry(*status_type).or_insert(0.0);
+            *value += amount * scale;
+
+            if *value >= resistances.status_threshold(*status_type) {
+                *value = 0.0;
+                let duration = status_type.effect_duration();
+                if duration > 0.0 {
+                    self.active.insert(*status_type, duration);
+                }
+                triggered.push(*status_type);
+            }
+        }
+
+        triggered
+    }
+
+    pub fn is_active(&self, status_type: StatusType) -> bool {
+        self.active.get(&status_type).is_some_and(|t| *t > 0.0)
+    }
+
+    // 중력 역병 중에는 받는 피해 증가
+    pub fn damage_taken_multiplier(&self) -> f32 {
+        if self.is_active(StatusType::GravityBlight) {
+            1.2
+        } else {
+            1.0
+        }
+    }
+}
@@ -1,13 +1,25 @@
 use bevy::prelude::*;
+use crate::components::damage::{DamagePacket, DamageType, StatusType};
+use crate::systems::world_generation::WorldZone;
 
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct Enemy {
     pub ai_type: EnemyType,
     pub detection_range: f32,
     pub attack_range: f32,
     pub move_speed: f32,
     pub attack_damage: f32,
+    pub damage_type: DamageType,
+    pub status_buildup: Vec<(StatusType, f32)>,
     pub attack_cooldown: f32,
+    pub poise_damage: f32,
+    pub attack_hyper_armor: bool, // 공격 동작 중 슈퍼아머
+    pub soul_reward: i32,         // 처치 시 획득 소울
+    pub loot_table: Option<String>, // LootTableDatabase 키 - 처치 시 아이템 드롭
+    pub preferred_range: f32,       // 원거리 적 - 사격하며 유지하려는 거리
+    pub retreat_range: f32,         // 원거리 적 - 플레이어가 이보다 가까우면 물러남
+    pub projectile_speed: f32,
+    pub boss_id: Option<String>,    // BossDatabase 키 - EnemyType::Boss 전용
 }
 
 impl Default for Enemy {
@@ -18,11 +30,78 @@ impl Default for Enemy {
             attack_range: 2.0,
             move_speed: 3.0,
             attack_damage: 20.0,
+            damage_type: DamageType::Strike,
+            status_buildup: Vec::new(),
             attack_cooldown: 2.0,
+            poise_damage: 15.0,
+            attack_hyper_armor: false,
+            soul_reward: 50,
+            loot_table: None,
+            preferred_range: 0.0,
+            retreat_range: 0.0,
+            projectile_speed: 25.0,
+            boss_id: None,
         }
     }
 }
 
+impl Enemy {
+    // 기본 궁수 - 거리를 벌리고 화살을 쏨
+    pub fn archer() -> Self {
+        Self {
+            ai_type: EnemyType::Archer,
+            detection_range: 20.0,
+            attack_range: 18.0,
+            preferred_range: 12.0,
+            retreat_range: 6.0,
+            move_speed: 2.5,
+            attack_damage: 14.0,
+            damage_type: DamageType::Thrust,
+            attack_cooldown: 2.5,
+            poise_damage: 8.0,
+            soul_reward: 80,
+            ..default()
+        }
+    }
+
+    pub fn is_ranged(&self) -> bool {
+        matches!(self.ai_type, EnemyType::Archer)
+    }
+
+    pub fn attack_packet(&self) -> DamagePacket {
+        DamagePacket {
+            amounts: vec![(self.damage_type, self.attack_damage)],
+            status_buildup: self.status_buildup.clone(),
+        }
+    }
+}
+
+// 적 스폰 정의 - 월드가 초기화될 때마다 이 정의대로 적을 다시 생성 (보스 제외)
+#[derive(Component, Clone)]
+pub struct EnemySpawnDefinition {
+    pub position: Vec3,
+    pub rotation: Quat,
+    pub enemy: Enemy,
+    pub max_health: f32,
+    pub max_poise: f32,
+    pub zone: WorldZone,
+    pub patrol: Option<PatrolPath>,  // 있으면 생성된 적이 이 경로를 순찰
+}
+
+impl EnemySpawnDefinition {
+    pub fn is_boss(&self) -> bool {
+        matches!(self.enemy.ai_type, EnemyType::Boss)
+    }
+}
+
+// 적이 생성된 스폰 정의 엔티티
+#[derive(Component)]
+pub struct SpawnedFrom(pub Entity);
+
+// 적이 속한 지역 - 지역별 저항 데이터를 적용하는 데 사용
+#[derive(Component, Clone, Copy)]
+pub struct EnemyZone(pub WorldZone);
+
 #[derive(Debug, Clone, Copy)]
 pub enum EnemyType {
     BasicMelee,
@@ -48,13 +127,86 @@ pub enum AIStateType {
     Attack,
     SearchLastKnown,
     Stunned,
+    Staggered,
+}
+
+// 적의 시야 - 시야각/거리/가림 판정으로 플레이어를 본 만큼 경계도가 차오르고, 다 차면 발견
+#[derive(Component, Clone)]
+pub struct Perception {
+    pub sight_range: f32,
+    pub focus_angle: f32,       // 정면 시야 반각 (라디안) - 이 안에서는 빠르게 알아챔
+    pub peripheral_angle: f32,  // 주변 시야 반각 (라디안) - 보이긴 하지만 천천히 알아챔
+    pub focus_gain: f32,        // 정면 시야에서 초당 경계도 증가량 (가까울수록 더 빠름)
+    pub peripheral_gain: f32,
+    pub decay_rate: f32,        // 보이지 않을 때 초당 경계도 감소량
+    pub awareness: f32,         // 0.0 ~ 1.0
+    pub alerted: bool,          // 경계도가 다 차서 발견한 상태 - 경계도가 0 이 되거나 추적을 포기할 때까지 유지
+    pub sees_player: bool,
+    pub time_since_seen: f32,
+}
+
+impl Default for Perception {
+    fn default() -> Self {
+        Self {
+            sight_range: 8.0,
+            focus_angle: 35f32.to_radians(),
+            peripheral_angle: 80f32.to_radians(),
+            focus_gain: 2.0,
+            peripheral_gain: 0.6,
+            decay_rate: 0.3,
+            awareness: 0.0,
+            alerted: false,
+            sees_player: false,
+            time_since_seen: f32::INFINITY,
+        }
+    }
 }
 
+impl Perception {
+    pub fn with_sight_range(sight_range: f32) -> Self {
+        Self {
+            sight_range,
+            ..default()
+        }
+    }
+
+    pub fn is_alerted(&self) -> bool {
+        self.alerted
+    }
+
+    // 공격을 받는 등 확실하게 플레이어를 알아챔
+    pub fn alert(&mut self) {
+        self.awareness = 1.0;
+        self.alerted = true;
+        self.time_since_seen = 0.0;
+    }
+
+    // 추적을 포기하고 돌아감 - 남은 경계도가 다시 다 차야 재발견
+    pub fn stand_down(&mut self) {
+        self.alerted = false;
+    }
+}
+
+// 적 머리 위 경계도 표시 - 경계도만큼 커지고, 발견하면 색이 바뀜
 #[derive(Component)]
+pub struct AwarenessIndicator;
+
+// 순찰 경로 끝에 도달했을 때 - 처음으로 돌아가거나 (Loop) 왔던 길로 되돌아감 (PingPong)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PatrolMode {
+    #[default]
+    Loop,
+    PingPong,
+}
+
+#[derive(Component, Clone)]
 pub struct PatrolPath {
     pub points: Vec<Vec3>,
     pub current_index: usize,
     pub patrol_speed: f32,
+    pub mode: PatrolMode,
+    pub wait_time: f32,   // 웨이포인트에 도착한 뒤 멈춰 서 있는 시간
+    pub reversing: bool,  // PingPong - 되돌아가는 중
 }
 
 impl Default for PatrolPath {
@@ -63,6 +215,58 @@ impl Default for PatrolPath {
             points: vec![],
             current_index: 0,
             patrol_speed: 1.5,
+            mode: PatrolMode::Loop,
+            wait_time: 2.0,
+            reversing: false,
+        }
+    }
+}
+
+impl PatrolPath {
+    pub fn new(points: Vec<Vec3>, mode: PatrolMode) -> Self {
+        Self {
+            points,
+            mode,
+            ..default()
+        }
+    }
+
+    pub fn current_point(&self) -> Option<Vec3> {
+        self.points.get(self.current_index).copied()
+    }
+
+    // 다음 웨이포인트로 - 점이 하나뿐이면 제자리 경비
+    pub fn advance(&mut self) {
+        let count = self.points.len();
+        if count < 2 {
+            return;
+        }
+        match self.mode {
+            PatrolMode::Loop => self.current_index = (self.current_index + 1) % count,
+            PatrolMode::PingPong => {
+                if self.reversing && self.current_index == 0 {
+                    self.reversing = false;
+                } else if !self.reversing && self.current_index == count - 1 {
+                    self.reversing = true;
+                }
+                if self.reversing {
+                    self.current_index -= 1;
+                } else {
+                    self.current_index += 1;
+                }
+            }
         }
     }
-}
\ No newline at end of file
+
+    // 추적을 놓친 뒤 가장 가까운 웨이포인트부터 순찰 재개
+    pub fn rejoin_nearest(&mut self, position: Vec3) {
+        if let Some((index, _)) = self
+            .points
+            .iter()
+            .enumerate()
+            .min_by(|a, b| a.1.distance_squared(position).total_cmp(&b.1.distance_squared(position)))
+        {
+            self.current_index = index;
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use crate::resources::item::{ArmorSlot, ItemKind};
+
+// 회복 횟수 / 회복량 강화 상한
+pub const FLASK_MAX_CHARGES_CAP: u32 = 15;
+pub const FLASK_MAX_POTENCY_LEVEL: u32 = 10;
+
+// 회복 물약 - 체크포인트에서 휴식하면 다시 채워짐
+#[derive(Component)]
+pub struct HealingFlask {
+    pub charges: u32,
+    pub max_charges: u32,
+    pub potency_level: u32,
+    pub base_heal: f32,
+    pub heal_per_level: f32,  // 회복량 강화 1단계당 추가 회복량
+    pub drink_duration: f32,
+    pub heal_delay: f32,      // 마시기 시작 후 회복되는 시점 - 그 전에 끊기면 회복 없이 소모
+    pub drink_timer: f32,
+    pub healed: bool,
+}
+
+impl Default for HealingFlask {
+    fn default() -> Self {
+        Self {
+            charges: 3,
+            max_charges: 3,
+            potency_level: 0,
+            base_heal: 40.0,
+            heal_per_level: 8.0,
+            drink_duration: 1.4,
+            heal_delay: 0.8,
+            drink_timer: 0.0,
+            healed: false,
+        }
+    }
+}
+
+impl HealingFlask {
+    pub fn heal_amount(&self) -> f32 {
+        self.base_heal + self.heal_per_level * self.potency_level as f32
+    }
+
+    pub fn is_drinking(&self) -> bool {
+        self.drink_timer > 0.0
+    }
+
+    // 남은 횟수가 있으면 한 번 소모하고 마시기 시작
+    pub fn start_drinking(&mut self) -> bool {
+        if self.charges == 0 || self.is_drinking() {
+            return false;
+        }
+        self.charges -= 1;
+        self.drink_timer = self.drink_duration;
+        self.healed = false;
+        true
+    }
+
+    // 피격 등으로 끊김 - 이미 소모한 횟수는 돌려주지 않음
+    pub fn interrupt(&mut self) {
+        self.drink_timer = 0.0;
+    }
+
+    pub fn refill(&mut self) {
+        self.charges = self.max_charges;
+        self.drink_timer = 0.0;
+    }
+
+    pub fn can_upgrade(&self, upgrade: FlaskUpgrade) -> bool {
+        match upgrade {
+            FlaskUpgrade::Charges => self.max_charges < FLASK_MAX_CHARGES_CAP,
+            FlaskUpgrade::Potency => self.potency_level < FLASK_MAX_POTENCY_LEVEL,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum FlaskUpgrade {
+    Charges,  // 최대 횟수 +1
+    Potency,  // 회복량 +1단계
+}
+
+// 회복 물약 강화 요청 - 강화 아이템을 얻으면 발생
+#[derive(Event)]
+pub struct FlaskUpgradeEvent {
+    pub upgrade: FlaskUpgrade,
+}
+
+// 소지품 - 아이템 id (ItemDatabase 키) 와 개수
+#[derive(Component, Default)]
+pub struct Inventory {
+    pub items: Vec<InventoryItem>,
+}
+
+#[derive(Clone, Debug)]
+pub struct InventoryItem {
+    pub item_id: String,
+    pub quantity: u32,
+}
+
+impl Inventory {
+    pub fn add(&mut self, item_id: &str, quantity: u32) {
+        match self.items.iter_mut().find(|item| item.item_id == item_id) {
+            Some(item) => item.quantity += quantity,
+            None => self.items.push(InventoryItem {
+                item_id: item_id.to_string(),
+                quantity,
+            }),
+        }
+    }
+
+    pub fn count(&self, item_id: &str) -> u32 {
+        self.items
+            .iter()
+            .find(|item| item.item_id == item_id)
+            .map_or(0, |item| item.quantity)
+    }
+
+    // 개수가 모자라면 아무것도 빼지 않음
+    pub fn remove(&mut self, item_id: &str, quantity: u32) -> bool {
+        let Some(index) = self.items.iter().position(|item| item.item_id == item_id && item.quantity >= quantity) else {
+            return false;
+        };
+        self.items[index].quantity -= quantity;
+        if self.items[index].quantity == 0 {
+            self.items.remove(index);
+        }
+        true
+    }
+}
+
+pub const MAX_WEAPON_UPGRADE_LEVEL: u32 = 10;
+
+// 무기별 강화 단계와 닳은 내구도 - 무기를 바꿔 들어도 유지됨 (기록이 없으면 +0, 새 것)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct WeaponCondition {
+    pub upgrade_level: u32,
+    pub wear: f32,
+}
+
+// 강화 단계 / 내구도는 무기 종류 (item_id) 마다 하나 - 같은 무기를 여러 개 가지면 모두 같은 상태를 공유
+// 그래서 상인은 이미 가진 무기를 팔지 않고, 마지막 한 개를 팔면 기록도 지움
+#[derive(Component, Default)]
+pub struct WeaponConditions {
+    pub weapons: HashMap<String, WeaponCondition>,
+}
+
+impl WeaponConditions {
+    pub fn get(&self, item_id: &str) -> WeaponCondition {
+        self.weapons.get(item_id).copied().unwrap_or_default()
+    }
+
+    pub fn get_mut(&mut self, item_id: &str) -> &mut WeaponCondition {
+        self.weapons.entry(item_id.to_string()).or_default()
+    }
+
+    // 내구도 감소 - 최대 내구도 이상은 닳지 않음
+    pub fn wear(&mut self, item_id: &str, amount: f32, max_durability: f32) {
+        let condition = self.get_mut(item_id);
+        condition.wear = (condition.wear + amount).min(max_durability);
+    }
+
+    pub fn repair_all(&mut self) {
+        for condition in self.weapons.values_mut() {
+            condition.wear = 0.0;
+        }
+    }
+}
+
+// 장비 칸
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EquipSlot {
+    RightHand(usize),
+    LeftHand(usize),
+    Armor(ArmorSlot),
+    Ring(usize),
+}
+
+impl EquipSlot {
+    pub const ALL: [EquipSlot; 10] = [
+        EquipSlot::RightHand(0),
+        EquipSlot::RightHand(1),
+        EquipSlot::LeftHand(0),
+        EquipSlot::LeftHand(1),
+        EquipSlot::Armor(ArmorSlot::Head),
+        EquipSlot::Armor(ArmorSlot::Chest),
+        EquipSlot::Armor(ArmorSlot::Hands),
+        EquipSlot::Armor(ArmorSlot::Legs),
+        EquipSlot::Ring(0),
+        EquipSlot::Ring(1),
+    ];
+
+    pub fn label(&self) -> String {
+        match self {
+            EquipSlot::RightHand(index) => format!("Right Hand {}", index + 1),
+            EquipSlot::LeftHand(index) => format!("Left Hand {}", index + 1),
+            EquipSlot::Armor(slot) => format!("{:?}", slot),
+            EquipSlot::Ring(index) => format!("Ring {}", index + 1),
+        }
+    }
+
+    // 이 칸에 넣을 수 있는 아이템인지
+    pub fn accepts(&self, kind: &ItemKind) -> bool {
+        match (self, kind) {
+            (EquipSlot::RightHand(_), ItemKind::Weapon(_)) => true,
+            (EquipSlot::LeftHand(_), ItemKind::Weapon(_) | ItemKind::Shield(_)) => true,
+            (EquipSlot::Armor(slot), ItemKind::Armor(armor)) => armor.slot == *slot,
+            (EquipSlot::Ring(_), ItemKind::Ring(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+// 장착 중인 아이템 - 양손은 두 칸씩 있고 active 인덱스로 전환
+#[derive(Component, Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Equipment {
+    pub right_hand: [Option<String>; 2],
+    pub left_hand: [Option<String>; 2],
+    pub active_right: usize,
+    pub active_left: usize,
+    pub head: Option<String>,
+    pub chest: Option<String>,
+    pub hands: Option<String>,
+    pub legs: Option<String>,
+    pub rings: [Option<String>; 2],
+}
+
+impl Equipment {
+    pub fn get(&self, slot: EquipSlot) -> Option<&String> {
+        match slot {
+            EquipSlot::RightHand(index) => self.right_hand[index].as_ref(),
+            EquipSlot::LeftHand(index) => self.left_hand[index].as_ref(),
+            EquipSlot::Armor(ArmorSlot::Head) => self.head.as_ref(),
+            EquipSlot::Armor(ArmorSlot::Chest) => self.chest.as_ref(),
+            EquipSlot::Armor(ArmorSlot::Hands) => self.hands.as_ref(),
+            EquipSlot::Armor(ArmorSlot::Legs) => self.legs.as_ref(),
+            EquipSlot::Ring(index) => self.rings[index].as_ref(),
+        }
+    }
+
+    pub fn set(&mut self, slot: EquipSlot, item_id: Option<String>) {
+        let target = match slot {
+            EquipSlot::RightHand(index) => &mut self.right_hand[index],
+            EquipSlot::LeftHand(index) => &mut self.left_hand[index],
+            EquipSlot::Armor(ArmorSlot::Head) => &mut self.head,
+            EquipSlot::Armor(ArmorSlot::Chest) => &mut self.chest,
+            EquipSlot::Armor(ArmorSlot::Hands) => &mut self.hands,
+            EquipSlot::Armor(ArmorSlot::Legs) => &mut self.legs,
+            EquipSlot::Ring(index) => &mut self.rings[index],
+        };
+        *target = item_id;
+    }
+
+    pub fn active_right_hand(&self) -> Option<&String> {
+        self.right_hand[self.active_right].as_ref()
+    }
+
+    pub fn active_left_hand(&self) -> Option<&String> {
+        self.left_hand[self.active_left].as_ref()
+    }
+
+    // 무게에 포함되는 모든 장착 아이템 (들고 있지 않은 예비 무기 포함)
+    pub fn equipped_ids(&self) -> impl Iterator<Item = &String> {
+        EquipSlot::ALL.iter().filter_map(move |slot| self.get(*slot))
+    }
+
+    // 같은 아이템이 이미 다른 칸에 장착되어 있는지
+    pub fn is_equipped_elsewhere(&self, item_id: &str, slot: EquipSlot) -> bool {
+        EquipSlot::ALL
+            .iter()
+            .any(|other| *other != slot && self.get(*other).is_some_and(|id| id == item_id))
+    }
+}
+
+// 반지 등 장비에서 오는 최대치 배율 - CharacterStats 파생 수치에 곱해짐
+#[derive(Component)]
+pub struct EquipmentBonuses {
+    pub max_health_scale: f32,
+    pub max_stamina_scale: f32,
+    pub equip_load_scale: f32,
+}
+
+impl Default for EquipmentBonuses {
+    fn default() -> Self {
+        Self {
+            max_health_scale: 1.0,
+            max_stamina_scale: 1.0,
+            equip_load_scale: 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hand {
+    Right,
+    Left,
+}
+
+// 손에 든 무기/방패 모델 - 캐릭터 모델이 로드되면 손 뼈대 자식으로 옮겨짐
+#[derive(Component)]
+pub struct HeldItemModel {
+    pub owner: Entity,
+    pub hand: Hand,
+}
+
+// 월드에 놓인 아이템 - 빛나는 표식, 가까이서 상호작용하면 획득
+#[derive(Component)]
+pub struct ItemPickup {
+    pub item_id: String,
+    pub quantity: u32,
+    pub loot_id: Option<String>,  // 한 번만 얻는 배치 아이템 (적 전리품은 None)
+}
+
+// 상자 - 처음 열 때 내용물 지급, 열린 상태는 저장
+#[derive(Component)]
+pub struct Chest {
+    pub loot_id: String,
+    pub contents: Vec<(String, u32)>,
+    pub opened: bool,
+}
+
+// 상자 뚜껑 경첩 (상자 자식) - 열리는 정도 0.0 ~ 1.0
+#[derive(Component, Default)]
+pub struct ChestLid {
+    pub progress: f32,
+}
+
+// 아이템 획득 - 소지품 추가와 획득 알림에 사용
+#[derive(Event)]
+pub struct ItemAcquiredEvent {
+    pub item_id: String,
+    pub quantity: u32,
+}
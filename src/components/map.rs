@@ -1,8 +1,21 @@
 use bevy::prelude::*;
+use crate::systems::world_generation::WorldZone;
 
 #[derive(Component)]
 pub struct CheckpointMarker;
 
+// 휴식 가능한 체크포인트 - CheckpointMarker 가 붙은 엔티티에 자동으로 추가
+#[derive(Component)]
+pub struct Checkpoint {
+    pub id: String,
+    pub zone: WorldZone,
+    pub lit: bool,
+}
+
+// 체크포인트 휴식 / 사망 후 부활 시 발생 - 적 재생성 등 월드 초기화에 사용
+#[derive(Event)]
+pub struct WorldResetEvent;
+
 #[derive(Component)]
 pub struct MapTile;
 
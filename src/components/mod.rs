@@ -1,13 +1,23 @@
 pub mod player;
 pub mod combat;
+pub mod damage;
 pub mod stats;
+pub mod item;
 pub mod enemy;
+pub mod boss;
+pub mod npc;
+pub mod noise;
 pub mod ui;
 pub mod map;
 
 pub use player::*;
 pub use combat::*;
+pub use damage::*;
 pub use stats::*;
+pub use item::*;
 pub use enemy::*;
+pub use boss::*;
+pub use npc::*;
+pub use noise::*;
 pub use ui::*;
 pub use map::*;
\ No newline at end of file
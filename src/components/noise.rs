@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+
+// 소음 발생 - 반경 안에서 듣고 있는 적은 소리가 난 곳을 살피러 감
+#[derive(Event)]
+pub struct NoiseEvent {
+    pub source: Entity,
+    pub position: Vec3,
+    pub radius: f32,
+}
+
+// 밟으면 소음이 나는 바닥 (수정 파편 지대 등) - 월드 좌표 기준 바운딩 박스
+#[derive(Component)]
+pub struct SoundTrap {
+    pub min: Vec3,
+    pub max: Vec3,
+    pub sound_radius: f32,
+}
+
+impl SoundTrap {
+    pub fn contains(&self, position: Vec3) -> bool {
+        position.cmpge(self.min).all() && position.cmple(self.max).all()
+    }
+}
@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+// 상인 NPC - 가까이서 상호작용하면 상점 메뉴 (MerchantDatabase 키)
+#[derive(Component)]
+pub struct Merchant {
+    pub merchant_id: String,
+}
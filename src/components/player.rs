@@ -5,7 +5,14 @@ pub struct Player {
     pub speed: f32,
     pub is_rolling: bool,
     pub roll_timer: f32,
-    pub roll_duration: f32,
+    pub roll_duration: f32,          // 기본 구르기 시간 (중간 구르기 기준)
+    pub roll_speed_multiplier: f32,  // 구르기 속도 = speed * 배율
+    pub roll_stamina_cost: f32,
+    pub iframe_start: f32,           // 구르기 시간 중 무적 시작 지점 (0.0 ~ 1.0)
+    pub iframe_end: f32,             // 구르기 시간 중 무적 종료 지점 (0.0 ~ 1.0)
+    pub roll_type: RollType,         // 장비 중량으로 결정
+    pub current_roll_duration: f32,  // 진행 중인 구르기의 실제 시간
+    pub roll_direction: RollDirection,  // 캐릭터 정면 기준 구르기 방향
 }
 
 impl Default for Player {
@@ -15,6 +22,110 @@ impl Default for Player {
             is_rolling: false,
             roll_timer: 0.0,
             roll_duration: 0.5,
+            roll_speed_multiplier: 2.0,
+            roll_stamina_cost: 20.0,
+            iframe_start: 0.1,
+            iframe_end: 0.7,
+            roll_type: RollType::Medium,
+            current_roll_duration: 0.5,
+            roll_direction: RollDirection::Forward,
+        }
+    }
+}
+
+impl Player {
+    // 현재 구르기 종류가 적용된 (시간, 속도, 무적 시간 배율)
+    pub fn roll_profile(&self) -> (f32, f32, f32) {
+        let (duration_scale, speed_scale, iframe_scale) = self.roll_type.scales();
+        (
+            self.roll_duration * duration_scale,
+            self.speed * self.roll_speed_multiplier * speed_scale,
+            iframe_scale,
+        )
+    }
+
+    // 구르기 중 무적 구간인지
+    pub fn is_invulnerable(&self) -> bool {
+        if !self.is_rolling {
+            return false;
+        }
+
+        let (_, _, iframe_scale) = self.roll_profile();
+        let elapsed = self.current_roll_duration - self.roll_timer;
+        let start = self.current_roll_duration * self.iframe_start;
+        let length = self.current_roll_duration * (self.iframe_end - self.iframe_start) * iframe_scale;
+        elapsed >= start && elapsed <= start + length
+    }
+}
+
+// 캐릭터 정면 기준 구르기 방향 (이동 입력이 없으면 백스텝)
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum RollDirection {
+    #[default]
+    Forward,
+    Backward,
+    Left,
+    Right,
+    Backstep,
+}
+
+impl RollDirection {
+    // 캐릭터 정면(facing)과 구르기 벡터(roll)로 방향 결정
+    pub fn from_vectors(facing: Vec3, roll: Vec3) -> Self {
+        let facing = Vec3::new(facing.x, 0.0, facing.z).normalize_or_zero();
+        let roll = Vec3::new(roll.x, 0.0, roll.z).normalize_or_zero();
+        let forward_amount = facing.dot(roll);
+        let diagonal = std::f32::consts::FRAC_1_SQRT_2;
+
+        if forward_amount >= diagonal {
+            RollDirection::Forward
+        } else if forward_amount <= -diagonal {
+            RollDirection::Backward
+        } else if facing.cross(roll).y > 0.0 {
+            // 로컬 +Z 정면 기준 +Y 외적이면 왼쪽
+            RollDirection::Left
+        } else {
+            RollDirection::Right
+        }
+    }
+
+    pub fn animation_state(&self) -> AnimationState {
+        match self {
+            RollDirection::Forward => AnimationState::Roll,
+            RollDirection::Backward => AnimationState::RollBackward,
+            RollDirection::Left => AnimationState::RollLeft,
+            RollDirection::Right => AnimationState::RollRight,
+            RollDirection::Backstep => AnimationState::Backstep,
+        }
+    }
+}
+
+// 장비 중량에 따른 구르기 종류
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum RollType {
+    Fast,     // 중량 30% 이하
+    #[default]
+    Medium,   // 중량 70% 이하
+    Fat,      // 중량 70% 초과
+}
+
+impl RollType {
+    pub fn from_equip_load(ratio: f32) -> Self {
+        if ratio <= 0.3 {
+            RollType::Fast
+        } else if ratio <= 0.7 {
+            RollType::Medium
+        } else {
+            RollType::Fat
+        }
+    }
+
+    // (시간 배율, 속도 배율, 무적 시간 배율)
+    pub fn scales(&self) -> (f32, f32, f32) {
+        match self {
+            RollType::Fast => (0.9, 1.15, 1.2),
+            RollType::Medium => (1.0, 1.0, 1.0),
+            RollType::Fat => (1.4, 0.6, 0.5),
         }
     }
 }
@@ -25,9 +136,25 @@ pub enum AnimationState {
     Idle,
     Walk,
     Run,
+    WalkBackward,
+    StrafeLeft,
+    StrafeRight,
     Roll,
+    RollBackward,
+    RollLeft,
+    RollRight,
+    Backstep,
     Jump,
     Slash,
+    Slash2,
+    Slash3,
+    HeavyCharge,
+    HeavySlash,
+    Guard,
+    GuardBreak,
+    HitReact,
+    Drink,
+    Critical,
     Parry,
     Death,
 }
@@ -38,6 +165,19 @@ impl Default for AnimationState {
     }
 }
 
+impl AnimationState {
+    // animation_mapping.json 의 공격 키를 애니메이션 상태로 변환
+    pub fn from_attack_key(key: &str) -> Self {
+        match key {
+            "attack_2" => AnimationState::Slash2,
+            "attack_3" => AnimationState::Slash3,
+            "attack_heavy_charge" => AnimationState::HeavyCharge,
+            "attack_heavy" => AnimationState::HeavySlash,
+            _ => AnimationState::Slash,
+        }
+    }
+}
+
 // 애니메이션 상태 전환 관리
 #[derive(Component)]
 pub struct AnimationController {
@@ -63,16 +203,69 @@ impl Default for AnimationController {
 // 애니메이션 클립들
 #[derive(Component, Default)]
 pub struct AnimationClips {
+    pub animation_set: String,  // 현재 로드된 세트 - 무기의 세트와 다르면 다시 로드
     pub idle: Option<Handle<AnimationClip>>,
     pub walk: Option<Handle<AnimationClip>>,
     pub run: Option<Handle<AnimationClip>>,
+    pub walk_backward: Option<Handle<AnimationClip>>,
+    pub strafe_left: Option<Handle<AnimationClip>>,
+    pub strafe_right: Option<Handle<AnimationClip>>,
     pub roll: Option<Handle<AnimationClip>>,
+    pub roll_backward: Option<Handle<AnimationClip>>,
+    pub roll_left: Option<Handle<AnimationClip>>,
+    pub roll_right: Option<Handle<AnimationClip>>,
+    pub backstep: Option<Handle<AnimationClip>>,
     pub jump: Option<Handle<AnimationClip>>,
     pub slash: Option<Handle<AnimationClip>>,
+    pub slash_2: Option<Handle<AnimationClip>>,
+    pub slash_3: Option<Handle<AnimationClip>>,
+    pub heavy_charge: Option<Handle<AnimationClip>>,
+    pub heavy_slash: Option<Handle<AnimationClip>>,
+    pub guard: Option<Handle<AnimationClip>>,
+    pub guard_break: Option<Handle<AnimationClip>>,
+    pub hit_react: Option<Handle<AnimationClip>>,
+    pub drink: Option<Handle<AnimationClip>>,
+    pub critical: Option<Handle<AnimationClip>>,
     pub parry: Option<Handle<AnimationClip>>,
     pub death: Option<Handle<AnimationClip>>,
 }
 
+// 락온 대상과 해제 조건
+#[derive(Component)]
+pub struct LockOnState {
+    pub target: Option<Entity>,
+    pub max_distance: f32,      // 락온 가능한 거리
+    pub break_distance: f32,    // 대상이 이 거리를 넘으면 해제
+    pub view_angle: f32,        // 카메라 정면 기준 락온 가능한 각도 (라디안)
+    pub sight_grace: f32,       // 시야가 가려져도 락온을 유지하는 시간
+    pub sight_lost_timer: f32,
+    pub switch_threshold: f32,  // 대상 전환에 필요한 한 프레임 마우스 이동량 (픽셀)
+    pub switch_cooldown: f32,
+    pub switch_timer: f32,
+}
+
+impl Default for LockOnState {
+    fn default() -> Self {
+        Self {
+            target: None,
+            max_distance: 20.0,
+            break_distance: 25.0,
+            view_angle: std::f32::consts::FRAC_PI_3,
+            sight_grace: 1.0,
+            sight_lost_timer: 0.0,
+            switch_threshold: 40.0,
+            switch_cooldown: 0.4,
+            switch_timer: 0.0,
+        }
+    }
+}
+
+impl LockOnState {
+    pub fn is_locked(&self) -> bool {
+        self.target.is_some()
+    }
+}
+
 #[derive(Component)]
 pub struct CameraController {
     pub distance: f32,
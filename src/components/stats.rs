@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 #[derive(Component)]
 pub struct Health {
@@ -32,6 +33,89 @@ impl Default for Stamina {
     }
 }
 
+// 강인도 - 피격 시 깎이고 0이 되면 경직 (하던 동작이 끊김)
+#[derive(Component)]
+pub struct Poise {
+    pub current: f32,
+    pub max: f32,
+    pub regen_rate: f32,
+    pub regen_delay: f32,      // 마지막 피격 후 회복이 시작될 때까지의 시간
+    pub regen_timer: f32,
+    pub hyper_armor: bool,     // 슈퍼아머 - 강인도가 깎여도 경직되지 않음
+    pub stagger_duration: f32,
+    pub stagger_timer: f32,
+}
+
+impl Default for Poise {
+    fn default() -> Self {
+        Self {
+            current: 30.0,
+            max: 30.0,
+            regen_rate: 10.0,
+            regen_delay: 2.0,
+            regen_timer: 0.0,
+            hyper_armor: false,
+            stagger_duration: 0.6,
+            stagger_timer: 0.0,
+        }
+    }
+}
+
+impl Poise {
+    pub fn with_max(max: f32) -> Self {
+        Self {
+            current: max,
+            max,
+            ..default()
+        }
+    }
+
+    pub fn is_staggered(&self) -> bool {
+        self.stagger_timer > 0.0
+    }
+
+    // 강인도 피해 적용 - 경직이 발생하면 true
+    pub fn apply_damage(&mut self, amount: f32) -> bool {
+        self.current -= amount;
+        self.regen_timer = self.regen_delay;
+
+        if self.current > 0.0 || self.hyper_armor {
+            self.current = self.current.max(0.0);
+            return false;
+        }
+
+        // 경직 후 강인도는 최대치로 복구
+        self.current = self.max;
+        self.stagger_timer = self.stagger_duration;
+        true
+    }
+}
+
+// 장비 중량 - 비율에 따라 구르기 종류가 달라짐
+#[derive(Component)]
+pub struct EquipLoad {
+    pub current: f32,
+    pub max: f32,
+}
+
+impl Default for EquipLoad {
+    fn default() -> Self {
+        Self {
+            current: 20.0,
+            max: 60.0,
+        }
+    }
+}
+
+impl EquipLoad {
+    pub fn ratio(&self) -> f32 {
+        if self.max <= 0.0 {
+            return 1.0;
+        }
+        self.current / self.max
+    }
+}
+
 #[derive(Component, Default)]
 pub struct Souls {
     pub count: i32,
@@ -41,4 +125,100 @@ pub struct Souls {
 pub struct DeathMarker {
     pub soul_amount: i32,
     pub position: Vec3,
-}
\ No newline at end of file
+}
+
+// 레벨업으로 올리는 능력치
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Attribute {
+    Vigor,      // 생명력 - 최대 체력
+    Endurance,  // 지구력 - 최대 스테미나, 회복 속도
+    Vitality,   // 체력 - 장비 중량
+    Strength,   // 근력 - 무기 근력 보정
+    Dexterity,  // 기량 - 무기 기량 보정
+}
+
+impl Attribute {
+    pub const ALL: [Attribute; 5] = [
+        Attribute::Vigor,
+        Attribute::Endurance,
+        Attribute::Vitality,
+        Attribute::Strength,
+        Attribute::Dexterity,
+    ];
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Attribute::Vigor => "Vigor",
+            Attribute::Endurance => "Endurance",
+            Attribute::Vitality => "Vitality",
+            Attribute::Strength => "Strength",
+            Attribute::Dexterity => "Dexterity",
+        }
+    }
+}
+
+// 캐릭터 능력치 - 바뀌면 체력/스테미나/장비 중량/무기 보정이 다시 계산됨
+#[derive(Component, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CharacterStats {
+    pub level: u32,
+    pub vigor: u32,
+    pub endurance: u32,
+    pub vitality: u32,
+    pub strength: u32,
+    pub dexterity: u32,
+}
+
+impl Default for CharacterStats {
+    fn default() -> Self {
+        Self {
+            level: 1,
+            vigor: 10,
+            endurance: 10,
+            vitality: 10,
+            strength: 10,
+            dexterity: 10,
+        }
+    }
+}
+
+impl CharacterStats {
+    // 능력치 최대치
+    pub const MAX_ATTRIBUTE: u32 = 99;
+
+    pub fn get(&self, attribute: Attribute) -> u32 {
+        match attribute {
+            Attribute::Vigor => self.vigor,
+            Attribute::Endurance => self.endurance,
+            Attribute::Vitality => self.vitality,
+            Attribute::Strength => self.strength,
+            Attribute::Dexterity => self.dexterity,
+        }
+    }
+
+    fn get_mut(&mut self, attribute: Attribute) -> &mut u32 {
+        match attribute {
+            Attribute::Vigor => &mut self.vigor,
+            Attribute::Endurance => &mut self.endurance,
+            Attribute::Vitality => &mut self.vitality,
+            Attribute::Strength => &mut self.strength,
+            Attribute::Dexterity => &mut self.dexterity,
+        }
+    }
+
+    // 다음 레벨까지 필요한 소울 - 레벨이 오를수록 가파르게 증가
+    pub fn level_up_cost(&self) -> i32 {
+        let x = self.level as f32;
+        (0.02 * x.powi(3) + 3.06 * x.powi(2) + 105.6 * x + 400.0).round() as i32
+    }
+
+    // 능력치 1 증가 + 레벨 업 (최대치면 false)
+    pub fn level_up(&mut self, attribute: Attribute) -> bool {
+        let value = self.get_mut(attribute);
+        if *value >= Self::MAX_ATTRIBUTE {
+            return false;
+        }
+        *value += 1;
+        self.level += 1;
+        true
+    }
+}
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use crate::components::stats::Attribute;
 
 // UI 컴포넌트들
 #[derive(Component)]
@@ -10,15 +11,119 @@ pub struct StaminaBar;
 #[derive(Component)]
 pub struct SoulCounter;
 
+// 체력바 옆 회복 물약 남은 횟수
+#[derive(Component)]
+pub struct FlaskCounter;
+
+// 스테미나바 아래 든 무기 이름 / 강화 단계
+#[derive(Component)]
+pub struct WeaponStatus;
+
+// 무기 이름 옆 내구도 바
+#[derive(Component)]
+pub struct DurabilityBar;
+
+// 소울 카운터 숫자가 실제 값까지 굴러가는 연출
+#[derive(Component, Default)]
+pub struct SoulCounterAnimation {
+    pub displayed: f32,
+    pub last_count: i32,
+    pub flash_timer: f32,
+    pub gained: bool,  // 마지막 변화가 획득인지 (색상 구분)
+}
+
 #[derive(Component)]
 pub struct GameUI;
 
+// 화면 중앙 하단 - 가까운 아이템 / 상자 상호작용 안내
+#[derive(Component)]
+pub struct InteractionPrompt;
+
+// 화면 중앙 하단 - 아이템 획득 알림 (timer 가 끝나면 사라짐)
+#[derive(Component, Default)]
+pub struct ItemNotification {
+    pub timer: f32,
+}
+
+// 화면 하단 보스 체력바 - 보스전 중에만 표시
+#[derive(Component)]
+pub struct BossHealthBarRoot;
+
+#[derive(Component)]
+pub struct BossNameText;
+
+#[derive(Component)]
+pub struct BossHealthBar;
+
+// 화면 중앙 - 보스 처치 배너 (timer 가 끝나면 사라짐)
+#[derive(Component, Default)]
+pub struct BossDefeatedBanner {
+    pub timer: f32,
+}
+
 #[derive(Component)]
 pub struct PauseMenu;
 
 #[derive(Component)]
 pub struct DeathScreen;
 
+#[derive(Component)]
+pub struct FastTravelMenu;
+
+// 빠른 이동 메뉴 항목 - FastTravel::destinations 의 인덱스
+#[derive(Component)]
+pub struct FastTravelEntry(pub usize);
+
+#[derive(Component)]
+pub struct LoadingScreen;
+
+#[derive(Component)]
+pub struct LevelUpMenu;
+
+// 레벨업 메뉴 상단 - 레벨, 보유 소울, 다음 레벨 비용
+#[derive(Component)]
+pub struct LevelUpSummary;
+
+#[derive(Component)]
+pub struct LevelUpEntry(pub Attribute);
+
+#[derive(Component)]
+pub struct EquipmentMenu;
+
+// 장비 메뉴 상단 - 장비 중량, 강인도
+#[derive(Component)]
+pub struct EquipmentSummary;
+
+// 장비 메뉴 하단 - 장착할 수 없는 소지품 (소모품, 열쇠, 강화 재료)
+#[derive(Component)]
+pub struct EquipmentItemList;
+
+// 장비 메뉴 항목 - EquipSlot::ALL 의 인덱스
+#[derive(Component)]
+pub struct EquipmentEntry(pub usize);
+
+#[derive(Component)]
+pub struct SmithingMenu;
+
+// 강화 메뉴 상단 - 보유 강화 재료
+#[derive(Component)]
+pub struct SmithingSummary;
+
+// 강화 메뉴 항목 - SmithingMenuState::weapons 의 인덱스
+#[derive(Component)]
+pub struct SmithingEntry(pub usize);
+
+#[derive(Component)]
+pub struct ShopMenu;
+
+// 상점 메뉴 상단 - 상인 이름, 탭, 보유 소울
+#[derive(Component)]
+pub struct ShopSummary;
+
+// 상점 메뉴 항목 - 화면에 보이는 줄 번호 (목록이 길면 선택한 줄을 따라 스크롤)
+#[derive(Component)]
+pub struct ShopEntry(pub usize);
+
 // UI 업데이트를 위한 이벤트들
 #[derive(Event)]
 pub struct UpdateHealthUI {
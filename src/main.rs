@@ -32,14 +32,56 @@ fn main() {
             color: Color::WHITE,
             brightness: 300.0,
         })
+        .add_event::<DamageEvent>()
+        .add_event::<DeathEvent>()
+        .add_event::<WorldResetEvent>()
+        .add_event::<FlaskUpgradeEvent>()
+        .add_event::<ItemAcquiredEvent>()
+        .add_event::<BossEncounterStartedEvent>()
+        .add_event::<BossPhaseChangedEvent>()
+        .add_event::<BossDefeatedEvent>()
+        .add_event::<NoiseEvent>()
         .init_resource::<InputMapping>()
         .init_resource::<MouseLook>()
+        .init_resource::<MovesetDatabase>()
+        .init_resource::<ResistanceDatabase>()
+        .init_resource::<RespawnPoint>()
+        .init_resource::<LitCheckpoints>()
+        .init_resource::<FastTravel>()
+        .init_resource::<LevelUpMenuState>()
+        .init_resource::<ItemDatabase>()
+        .init_resource::<EquipmentMenuState>()
+        .init_resource::<SmithingMenuState>()
+        .init_resource::<LootTableDatabase>()
+        .init_resource::<CollectedLoot>()
+        .init_resource::<MerchantDatabase>()
+        .init_resource::<PurchasedStock>()
+        .init_resource::<ShopMenuState>()
+        .init_resource::<BossDatabase>()
+        .init_resource::<DefeatedBosses>()
+        .init_resource::<SoundTrapDatabase>()
+        .init_resource::<LoadedPlayerProgress>()
+        .init_resource::<AnimationMapping>()
         .init_resource::<MyAssets>()
         .init_resource::<world_objects::ObjectDatabase>()
         .init_resource::<world_generation::MaterialDatabase>()
         .add_systems(Startup, (
             setup_scene, 
             setup_ui, 
+            load_moveset_database,
+            load_resistance_database,
+            load_lit_checkpoints,
+            load_item_database,
+            load_loot_tables,
+            load_collected_loot,
+            load_player_progress,
+            load_merchant_database,
+            load_purchased_stock,
+            load_boss_database,
+            load_defeated_bosses,
+            load_animation_mapping,
+            load_sound_traps,
+            spawn_sound_traps.after(load_sound_traps),
             setup_simple_world.after(setup_scene),
             world_generation::setup_material_database,
             world_objects::spawn_world_objects.after(world_generation::setup_material_database),
@@ -48,19 +90,109 @@ fn main() {
         .add_systems(
             Update,
             (
+                update_roll_type.before(player_movement),
                 player_movement,
                 handle_input,
+                parry_system.before(guard_system),
+                guard_system.before(combat_system),
+                critical_attack_system.before(combat_system),
+                poise_system.before(combat_system),
+                status_effect_system,
                 combat_system,
+                hitbox_follow_system.before(hitbox_system),
                 hitbox_system,
                 weapon_system,
                 attack_effect_system,
+                soul_retrieval_system,
+                flask_system.before(combat_system),
+                flask_upgrade_system,
+                apply_character_stats,
+                apply_weapon_scaling.before(combat_system).before(critical_attack_system),
+            ).run_if(in_state(GameState::Playing))
+        )
+        // 장비 / 소지품 - 무기 전환이나 메뉴에서 바뀐 장비를 능력치보다 먼저 반영
+        .add_systems(
+            Update,
+            (
+                apply_equipment
+                    .after(weapon_system)
+                    .before(apply_character_stats)
+                    .before(apply_weapon_scaling),
+                // 피해 처리(무기 마모) 이후 - 능력치 보정은 Changed<Weapon> 으로 다음 프레임에 반영
+                apply_weapon_condition
+                    .after(apply_equipment)
+                    .after(hitbox_system)
+                    .after(damage_resolution_system),
+                open_equipment_menu,
+                attach_held_item_models.after(apply_equipment),
+            ).run_if(in_state(GameState::Playing))
+        )
+        // 아이템 줍기 / 상자 / 획득 처리, 상인
+        .add_systems(
+            Update,
+            (
+                init_collected_loot,
+                loot_interaction_system,
+                item_acquired_system.after(loot_interaction_system),
+                chest_lid_system,
+                // 열린 상자는 범위 판정에서 빠지므로 줍기보다 먼저 양보 여부 판단
+                open_shop_menu.before(loot_interaction_system),
+            ).run_if(in_state(GameState::Playing))
+        )
+        // 플레이어 진행 상황 불러오기 / 저장 - 획득 목록과 함께 재시작 후에도 유지
+        .add_systems(
+            Update,
+            (
+                apply_player_progress,
+                // 상점 / 레벨업 메뉴에서도 저장 - 한정 상품 구매 기록, 소모한 소울과 올린 능력치가 같은 프레임에 기록됨
+                save_player_progress
+                    .after(apply_player_progress)
+                    .after(item_acquired_system)
+                    .after(shop_menu_system)
+                    .after(level_up_menu_system)
+                    .run_if(
+                        in_state(GameState::Playing)
+                            .or(in_state(GameState::Shop))
+                            .or(in_state(GameState::LevelUp)),
+                    ),
+            )
+        )
+        // 피해 / 사망 처리 - 피해를 보내는 시스템들 이후에 한 번에 처리
+        .add_systems(
+            Update,
+            (
+                damage_resolution_system,
+                (death_system, soul_reward_system, soul_drop_system, loot_drop_system, boss_defeat_system),
+                corpse_system,
+            )
+                .chain()
+                .after(hitbox_system)
+                .after(critical_attack_system)
+                .after(status_effect_system)
+                .after(enemy_attack_system)
+                .after(projectile_system)
+                .run_if(in_state(GameState::Playing))
+        )
+        // 보스전 - 아레나 봉인, 페이즈별 행동, 페이즈 지형, 처치 / 초기화
+        .add_systems(
+            Update,
+            (
+                init_boss_arenas,
+                boss_arena_system,
+                fog_wall_system.after(boss_arena_system).after(boss_defeat_system),
+                boss_ai_system.before(damage_resolution_system),
+                arena_phase_element_system.after(boss_ai_system),
+                boss_reset_system,
             ).run_if(in_state(GameState::Playing))
         )
         // 카메라 시스템
         .add_systems(
             Update,
             (
+                lock_on_system,
+                lock_on_indicator_system.after(lock_on_system),
                 orbit_mouse,
+                frame_lock_on_target.after(lock_on_system).after(orbit_mouse),
                 zoom_mouse,
                 aim,
                 toggle_offset,
@@ -71,8 +203,17 @@ fn main() {
         .add_systems(
             Update,
             (
+                apply_zone_resistances,
+                perception_system.before(enemy_ai_system),
+                awareness_indicator_system.after(perception_system).after(enemy_ai_system),
+                player_noise_system,
+                combat_noise_system,
+                // 소리를 들은 적은 같은 프레임에 AI 가 바로 살피러 출발
+                hearing_system.after(player_noise_system).after(combat_noise_system).before(enemy_ai_system),
                 enemy_ai_system,
-                enemy_attack_system,
+                // 공격 상태에서 타이머가 다 된 프레임에 공격 - AI 가 타이머를 다시 채우기 전에 실행
+                enemy_attack_system.before(enemy_ai_system),
+                projectile_system,
             ).run_if(in_state(GameState::Playing))
         )
         // UI 시스템
@@ -82,6 +223,12 @@ fn main() {
                 update_health_ui,
                 update_stamina_ui,
                 update_souls_ui,
+                update_flask_ui,
+                update_weapon_ui,
+                update_interaction_prompt,
+                update_item_notification,
+                update_boss_health_ui,
+                update_boss_defeated_banner,
             ).run_if(in_state(GameState::Playing))
         )
         // 애니메이션 시스템
@@ -90,7 +237,7 @@ fn main() {
             (
                 update_animation_state,
                 play_animations,
-                load_aurora_animations,
+                load_weapon_animations.after(apply_equipment),
                 setup_animation_player,
             ).run_if(in_state(GameState::Playing))
         )
@@ -120,11 +267,51 @@ fn main() {
         .add_systems(OnEnter(GameState::Paused), setup_pause_menu)
         .add_systems(OnExit(GameState::Paused), cleanup_pause_menu)
         .add_systems(OnEnter(GameState::Death), setup_death_screen)
-        .add_systems(OnExit(GameState::Death), cleanup_death_screen)
+        .add_systems(OnExit(GameState::Death), (cleanup_death_screen, respawn_player))
+        // 체크포인트 / 월드 초기화
+        .add_systems(
+            Update,
+            (
+                init_checkpoints,
+                checkpoint_interaction_system.before(loot_interaction_system),
+                open_fast_travel_menu,
+                open_level_up_menu,
+                open_smithing_menu,
+                checkpoint_light_system,
+                spawn_initial_enemies,
+                reset_enemies_system,
+                flask_refill_system,
+                weapon_repair_system,
+            ).run_if(in_state(GameState::Playing))
+        )
         .add_systems(
             Update,
             handle_death_ui.run_if(in_state(GameState::Death))
         )
+        // 체크포인트 메뉴 - 빠른 이동 (메뉴 → 로딩 → 도착), 레벨업, 무기 강화
+        .add_systems(OnEnter(GameState::FastTravel), setup_fast_travel_menu)
+        .add_systems(OnExit(GameState::FastTravel), cleanup_fast_travel_menu)
+        .add_systems(OnEnter(GameState::LevelUp), setup_level_up_menu)
+        .add_systems(OnExit(GameState::LevelUp), cleanup_level_up_menu)
+        .add_systems(OnEnter(GameState::Smithing), setup_smithing_menu)
+        .add_systems(OnExit(GameState::Smithing), cleanup_smithing_menu)
+        .add_systems(OnEnter(GameState::Inventory), setup_equipment_menu)
+        .add_systems(OnExit(GameState::Inventory), cleanup_equipment_menu)
+        .add_systems(OnEnter(GameState::Shop), setup_shop_menu)
+        .add_systems(OnExit(GameState::Shop), cleanup_shop_menu)
+        .add_systems(OnEnter(GameState::Loading), begin_fast_travel)
+        .add_systems(OnExit(GameState::Loading), cleanup_loading_screen)
+        .add_systems(
+            Update,
+            (
+                fast_travel_menu_system.run_if(in_state(GameState::FastTravel)),
+                fast_travel_loading_system.run_if(in_state(GameState::Loading)),
+                level_up_menu_system.run_if(in_state(GameState::LevelUp)),
+                equipment_menu_system.run_if(in_state(GameState::Inventory)),
+                smithing_menu_system.run_if(in_state(GameState::Smithing)),
+                shop_menu_system.run_if(in_state(GameState::Shop)),
+            )
+        )
         .add_systems(
             OnEnter(AssetLoaderState::Done),
             update_gltf_scene
@@ -140,22 +327,19 @@ fn main() {
         .run();
 }
 
-fn setup_scene(
-    mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-) {
+fn setup_scene(mut commands: Commands) {
     // 월드 생성 시스템이 지형을 생성함
 
     // 플레이어 생성
     let player_entity = commands.spawn((
         Transform::from_xyz(0.0, 2.0, 0.0),  // 지면 위
         Player::default(),
-        Health { current: 100.0, max: 100.0 },
-        Stamina { current: 100.0, max: 100.0, regen_rate: 20.0 },
+        Health::default(),   // 최대치는 CharacterStats 로 계산
+        Stamina::default(),
         Souls::default(),
         Weapon::default(),
         AttackState::default(),
+        GuardState::default(),
         RigidBody::Dynamic,
         Collider::capsule_y(1.0, 0.5),
         LockedAxes::ROTATION_LOCKED,
@@ -169,6 +353,40 @@ fn setup_scene(
         ThirdPersonCameraTarget,  // Third person camera target
         AnimationController::default(),  // 애니메이션 FSM 컨트롤러
         AnimationClips::default(),  // 애니메이션 클립들 (나중에 로드)
+        ParryState::default(),
+        CriticalAttackState::default(),
+        EquipLoad::default(),
+        Poise::default(),
+        Resistances::default(),
+        StatusBuildup::default(),
+        LockOnState::default(),
+        HealingFlask::default(),
+        CharacterStats::default(),
+    ));
+
+    // 시작 장비 - 예비 무기와 방어구는 소지품에만 넣어 둠
+    let mut inventory = Inventory::default();
+    for item_id in [
+        "straight_sword",
+        "greatsword",
+        "wooden_shield",
+        "knight_helm",
+        "knight_armor",
+        "knight_gauntlets",
+        "knight_leggings",
+        "ring_of_vigor",
+    ] {
+        inventory.add(item_id, 1);
+    }
+    commands.entity(player_entity).insert((
+        inventory,
+        Equipment {
+            right_hand: [Some("straight_sword".to_string()), Some("greatsword".to_string())],
+            left_hand: [Some("wooden_shield".to_string()), None],
+            ..default()
+        },
+        EquipmentBonuses::default(),
+        WeaponConditions::default(),
     ));
 
     // 임시 캡슐 메시는 제거 - Aurora 모델만 표시
@@ -180,28 +398,53 @@ fn setup_scene(
         ThirdPersonCamera {
             zoom: Zoom::new(5.0, 15.0),  // 소울라이크 스타일 거리
             sensitivity: Vec2::new(0.5, 0.5),  // 민감도 조정
-            aim_enabled: true,  // 조준 기능 활성화
+            aim_enabled: false,  // 우클릭은 차지 강공격에 사용 - 조준 모드 끔
             offset_enabled: true,  // 어깨 너머 시점
-            offset_toggle_enabled: true,  // E키로 좌/우 전환
+            offset_toggle_enabled: true,  // V키로 좌/우 전환 (E는 상호작용)
+            offset_toggle_key: KeyCode::KeyV,
             ..default()
         },
     ));
 
-    // 테스트용 적 생성 - Z1 지역에 배치
+    // 테스트용 적 스폰 정의 - Z1 지역에 배치 (실제 생성은 spawn_initial_enemies)
     commands.spawn((
-        Mesh3d(meshes.add(Capsule3d::default().mesh())),
-        MeshMaterial3d(materials.add(StandardMaterial {
-            base_color: Color::srgb(0.3, 0.8, 0.3),
-            ..default()
-        })),
-        Transform::from_xyz(10.0, 2.0, 5.0),  // 플레이어 근처
-        Enemy::default(),
-        Health { current: 50.0, max: 50.0 },
-        AIState::default(),
-        RigidBody::Dynamic,
-        Collider::capsule_y(1.0, 0.5),
-        LockedAxes::ROTATION_LOCKED,
-        Velocity::default(),
+        EnemySpawnDefinition {
+            position: Vec3::new(10.0, 2.0, 5.0),  // 플레이어 근처
+            rotation: Quat::IDENTITY,
+            enemy: Enemy {
+                loot_table: Some("hollow_soldier".to_string()),
+                ..default()
+            },
+            max_health: 50.0,
+            max_poise: 20.0,
+            zone: WorldZone::PilgrimsAscent,
+            // 스폰 위치 주변을 사각형으로 순찰
+            patrol: Some(PatrolPath::new(
+                vec![
+                    Vec3::new(10.0, 2.0, 5.0),
+                    Vec3::new(16.0, 2.0, 5.0),
+                    Vec3::new(16.0, 2.0, 11.0),
+                    Vec3::new(10.0, 2.0, 11.0),
+                ],
+                PatrolMode::Loop,
+            )),
+        },
+        Name::new("EnemySpawn_Test"),
+    ));
+    commands.spawn((
+        EnemySpawnDefinition {
+            position: Vec3::new(-14.0, 2.0, 12.0),
+            rotation: Quat::IDENTITY,
+            enemy: Enemy {
+                loot_table: Some("hollow_soldier".to_string()),
+                ..Enemy::archer()
+            },
+            max_health: 35.0,
+            max_poise: 10.0,
+            zone: WorldZone::PilgrimsAscent,
+            patrol: None,
+        },
+        Name::new("EnemySpawn_TestArcher"),
     ));
 
     // 조명은 월드 생성 시스템에서 추가됨
@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+// 무기별 애니메이션 세트 정의 파일 (세트 이름 → 애니메이션 키 → glb 파일)
+pub const ANIMATION_MAPPING_PATH: &str = "assets/Animations_GLTF/animation_mapping.json";
+
+#[derive(Resource, Default)]
+pub struct AnimationMapping {
+    pub sets: HashMap<String, HashMap<String, String>>,
+}
+
+impl AnimationMapping {
+    pub fn get(&self, set: &str) -> Option<&HashMap<String, String>> {
+        self.sets.get(set)
+    }
+}
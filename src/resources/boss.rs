@@ -0,0 +1,88 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use crate::components::DamageType;
+
+// 보스 기획 데이터 파일
+pub const BOSS_DATA_PATH: &str = "assets/metadata/bosses.json";
+// 처치한 보스 저장 파일 - 처치한 보스는 다시 나오지 않고 안개벽도 열린 채로 유지
+pub const DEFEATED_BOSSES_SAVE_PATH: &str = "saves/defeated_bosses.json";
+
+fn default_weight() -> f32 {
+    1.0
+}
+
+fn default_recovery() -> f32 {
+    1.0
+}
+
+// 공격 판정 형태
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(tag = "kind")]
+pub enum BossAttackKind {
+    Melee { range: f32, angle: f32 },  // 정면 부채꼴 (angle 은 라디안, 좌우 합친 폭의 절반)
+    Area { radius: f32 },              // 보스 주변 전체
+    Volley { range: f32, count: u32, spread: f32, speed: f32 },  // 화살/파편 여러 발을 부채꼴로 발사
+}
+
+impl BossAttackKind {
+    // 이 거리 안에 있어야 공격을 고름
+    pub fn reach(&self) -> f32 {
+        match self {
+            BossAttackKind::Melee { range, .. } => *range,
+            BossAttackKind::Area { radius } => *radius,
+            BossAttackKind::Volley { range, .. } => *range,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BossAttack {
+    pub name: String,
+    #[serde(default = "default_weight")]
+    pub weight: f32,     // 가중치 - 쓸 수 있는 공격 중에서 이 비율로 고름
+    pub cooldown: f32,   // 같은 공격을 다시 쓰기까지의 시간
+    pub windup: f32,     // 예비 동작 - 이 시간이 지나야 판정
+    #[serde(default = "default_recovery")]
+    pub recovery: f32,   // 판정 후 다음 행동까지 빈틈
+    pub damage: f32,
+    pub damage_type: DamageType,
+    pub poise_damage: f32,
+    #[serde(flatten)]
+    pub kind: BossAttackKind,
+}
+
+// 체력 비율이 health_threshold 이하로 떨어지면 이 페이즈로 넘어감 (첫 페이즈는 1.0)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BossPhaseData {
+    pub health_threshold: f32,
+    pub move_speed: f32,
+    pub attacks: Vec<BossAttack>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BossDefinition {
+    pub name: String,
+    pub max_health: f32,
+    pub max_poise: f32,
+    pub soul_reward: i32,
+    #[serde(default)]
+    pub loot_table: Option<String>,
+    pub phases: Vec<BossPhaseData>,
+}
+
+#[derive(Resource, Default)]
+pub struct BossDatabase {
+    pub bosses: HashMap<String, BossDefinition>,
+}
+
+impl BossDatabase {
+    pub fn get(&self, boss_id: &str) -> Option<&BossDefinition> {
+        self.bosses.get(boss_id)
+    }
+}
+
+#[derive(Resource, Default, Serialize, Deserialize)]
+pub struct DefeatedBosses {
+    pub ids: BTreeSet<String>,
+}
@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+// 점화한 체크포인트 저장 파일
+pub const LIT_CHECKPOINTS_SAVE_PATH: &str = "saves/lit_checkpoints.json";
+
+// 사망 시 부활 위치 - 마지막으로 휴식한 체크포인트
+#[derive(Resource)]
+pub struct RespawnPoint {
+    pub position: Vec3,
+    pub checkpoint: Option<Entity>,
+}
+
+impl Default for RespawnPoint {
+    fn default() -> Self {
+        Self {
+            position: Vec3::new(0.0, 2.0, 0.0),  // 플레이어 시작 위치
+            checkpoint: None,
+        }
+    }
+}
+
+// 점화한 체크포인트 id 목록 - 재시작해도 유지되도록 디스크에 저장
+#[derive(Resource, Default, Serialize, Deserialize)]
+pub struct LitCheckpoints {
+    pub ids: BTreeSet<String>,
+}
+
+// 레벨업 메뉴에서 선택한 능력치 (Attribute::ALL 인덱스)
+#[derive(Resource, Default)]
+pub struct LevelUpMenuState {
+    pub selected: usize,
+}
+
+// 빠른 이동 메뉴 선택 상태와 진행 중인 이동
+#[derive(Resource, Default)]
+pub struct FastTravel {
+    pub destinations: Vec<Entity>,  // 메뉴 표시 순서 (지역별로 정렬)
+    pub selected: usize,
+    pub destination: Option<Entity>,
+    pub loading_timer: f32,
+}
@@ -7,10 +7,20 @@ pub struct InputMapping {
     pub move_left: KeyCode,
     pub move_right: KeyCode,
     pub attack: MouseButton,
+    pub heavy_attack: MouseButton,  // 차지 강공격
     pub roll: KeyCode,
+    pub sprint: KeyCode,
     pub interact: KeyCode,
+    pub use_item: KeyCode,  // 회복 물약 마시기
     pub pause: KeyCode,
     pub block: KeyCode,  // 방어/패링
+    pub lock_on: KeyCode,
+    pub travel: KeyCode,  // 점화한 체크포인트에서 빠른 이동
+    pub level_up: KeyCode,  // 점화한 체크포인트에서 레벨업
+    pub upgrade: KeyCode,  // 점화한 체크포인트에서 무기 강화
+    pub inventory: KeyCode,  // 장비 / 소지품 메뉴
+    pub switch_right_hand: KeyCode,  // 오른손 무기 전환
+    pub switch_left_hand: KeyCode,   // 왼손 무기 / 방패 전환
 }
 
 impl Default for InputMapping {
@@ -21,10 +31,20 @@ impl Default for InputMapping {
             move_left: KeyCode::KeyA,
             move_right: KeyCode::KeyD,
             attack: MouseButton::Left,
+            heavy_attack: MouseButton::Right,
             roll: KeyCode::Space,
+            sprint: KeyCode::ControlLeft,  // Shift는 방어에 사용
             interact: KeyCode::KeyE,
+            use_item: KeyCode::KeyR,
             pause: KeyCode::Escape,
             block: KeyCode::ShiftLeft,  // Shift 키로 방어
+            lock_on: KeyCode::KeyQ,
+            travel: KeyCode::KeyT,
+            level_up: KeyCode::KeyL,
+            upgrade: KeyCode::KeyU,
+            inventory: KeyCode::KeyI,
+            switch_right_hand: KeyCode::Digit1,
+            switch_left_hand: KeyCode::Digit2,
         }
     }
 }
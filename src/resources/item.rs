@@ -0,0 +1,167 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use crate::components::{DamageType, FlaskUpgrade, StatusType, Weapon};
+
+// 아이템 기획 데이터 파일
+pub const ITEM_DATA_PATH: &str = "assets/metadata/items.json";
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArmorSlot {
+    Head,
+    Chest,
+    Hands,
+    Legs,
+}
+
+impl ArmorSlot {
+    pub const ALL: [ArmorSlot; 4] = [ArmorSlot::Head, ArmorSlot::Chest, ArmorSlot::Hands, ArmorSlot::Legs];
+}
+
+fn default_scale() -> f32 {
+    1.0
+}
+
+fn default_durability() -> f32 {
+    100.0
+}
+
+fn default_animation_set() -> String {
+    "Aurora".to_string()
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct WeaponData {
+    pub damage: f32,
+    pub damage_type: DamageType,
+    #[serde(default)]
+    pub elemental_damage: Vec<(DamageType, f32)>,
+    #[serde(default)]
+    pub status_buildup: Vec<(StatusType, f32)>,
+    pub attack_range: f32,
+    pub attack_cooldown: f32,
+    pub stamina_cost: f32,
+    pub moveset_id: String,
+    pub guard_absorption: f32,
+    pub guard_stability: f32,
+    pub critical_multiplier: f32,
+    pub strength_scaling: f32,
+    pub dexterity_scaling: f32,
+    #[serde(default = "default_durability")]
+    pub max_durability: f32,
+    #[serde(default = "default_animation_set")]
+    pub animation_set: String,   // animation_mapping.json 의 세트 이름
+    #[serde(default)]
+    pub model: Option<String>,   // assets 기준 glb 경로
+    #[serde(default = "default_scale")]
+    pub model_scale: f32,
+}
+
+impl WeaponData {
+    pub fn to_weapon(&self) -> Weapon {
+        Weapon {
+            damage: self.damage,
+            damage_type: self.damage_type,
+            elemental_damage: self.elemental_damage.clone(),
+            status_buildup: self.status_buildup.clone(),
+            attack_range: self.attack_range,
+            attack_cooldown: self.attack_cooldown,
+            stamina_cost: self.stamina_cost,
+            moveset_id: self.moveset_id.clone(),
+            guard_absorption: self.guard_absorption,
+            guard_stability: self.guard_stability,
+            critical_multiplier: self.critical_multiplier,
+            strength_scaling: self.strength_scaling,
+            dexterity_scaling: self.dexterity_scaling,
+            attribute_bonus: 0.0,
+            animation_set: self.animation_set.clone(),
+            upgrade_level: 0,
+            durability: self.max_durability,
+            max_durability: self.max_durability,
+        }
+    }
+}
+
+// 왼손 방패 - 들고 있으면 가드 수치를 무기 대신 방패 값으로 사용
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ShieldData {
+    pub guard_absorption: f32,
+    pub guard_stability: f32,
+    #[serde(default = "default_durability")]
+    pub max_durability: f32,
+    #[serde(default)]
+    pub model: Option<String>,
+    #[serde(default = "default_scale")]
+    pub model_scale: f32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ArmorData {
+    pub slot: ArmorSlot,
+    pub poise: f32,
+    #[serde(default)]
+    pub absorption: HashMap<DamageType, f32>,  // 속성별 피해 감소율 (부위 합산)
+}
+
+// 반지 - 최대치 배율
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RingData {
+    #[serde(default = "default_scale")]
+    pub max_health_scale: f32,
+    #[serde(default = "default_scale")]
+    pub max_stamina_scale: f32,
+    #[serde(default = "default_scale")]
+    pub equip_load_scale: f32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(tag = "kind")]
+pub enum ItemKind {
+    Weapon(WeaponData),
+    Shield(ShieldData),
+    Armor(ArmorData),
+    Ring(RingData),
+    Consumable,
+    KeyItem,
+    UpgradeMaterial,  // 무기 강화 재료
+    FlaskUpgrade { upgrade: FlaskUpgrade },  // 얻는 즉시 회복 물약 강화 (소지품에 남지 않음)
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ItemDefinition {
+    pub name: String,
+    #[serde(default)]
+    pub weight: f32,
+    #[serde(default)]
+    pub sell_value: i32,  // 상인에게 팔 때 받는 소울 (0 이면 팔 수 없음)
+    #[serde(flatten)]
+    pub kind: ItemKind,
+}
+
+#[derive(Resource, Default)]
+pub struct ItemDatabase {
+    pub items: HashMap<String, ItemDefinition>,
+}
+
+impl ItemDatabase {
+    pub fn get(&self, item_id: &str) -> Option<&ItemDefinition> {
+        self.items.get(item_id)
+    }
+
+    pub fn name<'a>(&'a self, item_id: &'a str) -> &'a str {
+        self.get(item_id).map_or(item_id, |item| item.name.as_str())
+    }
+}
+
+// 장비 메뉴에서 선택한 칸 (EquipSlot::ALL 인덱스)
+#[derive(Resource, Default)]
+pub struct EquipmentMenuState {
+    pub selected: usize,
+}
+
+// 강화 메뉴 - 가진 무기 목록 (메뉴를 열 때 소지품에서 수집)
+#[derive(Resource, Default)]
+pub struct SmithingMenuState {
+    pub weapons: Vec<String>,
+    pub selected: usize,
+}
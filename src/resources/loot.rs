@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+
+// 적 전리품 테이블 데이터 파일
+pub const LOOT_TABLE_PATH: &str = "assets/metadata/loot_tables.json";
+// 열어 본 상자 / 주운 월드 아이템 저장 파일
+pub const COLLECTED_LOOT_SAVE_PATH: &str = "saves/collected_loot.json";
+
+fn default_quantity() -> u32 {
+    1
+}
+
+// 전리품 한 줄 - 항목마다 따로 확률 판정
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct LootDrop {
+    pub item_id: String,
+    #[serde(default = "default_quantity")]
+    pub quantity: u32,
+    pub chance: f32,  // 0.0 ~ 1.0
+}
+
+#[derive(Resource, Default)]
+pub struct LootTableDatabase {
+    pub tables: HashMap<String, Vec<LootDrop>>,
+}
+
+impl LootTableDatabase {
+    pub fn get(&self, table_id: &str) -> Option<&Vec<LootDrop>> {
+        self.tables.get(table_id)
+    }
+}
+
+// 한 번만 얻을 수 있는 상자 / 월드 아이템 id - 재시작해도 다시 나오지 않도록 디스크에 저장
+#[derive(Resource, Default, Serialize, Deserialize)]
+pub struct CollectedLoot {
+    pub ids: BTreeSet<String>,
+}
@@ -0,0 +1,77 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+// 상인 판매 목록 데이터 파일
+pub const MERCHANT_DATA_PATH: &str = "assets/metadata/merchants.json";
+// 수량 한정 상품 구매 기록 저장 파일
+pub const MERCHANT_STOCK_SAVE_PATH: &str = "saves/merchant_stock.json";
+
+// 판매 상품 한 줄 - quantity 가 없으면 무제한
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct StockEntry {
+    pub item_id: String,
+    pub price: i32,
+    #[serde(default)]
+    pub quantity: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct MerchantDefinition {
+    pub name: String,
+    pub stock: Vec<StockEntry>,
+}
+
+#[derive(Resource, Default)]
+pub struct MerchantDatabase {
+    pub merchants: HashMap<String, MerchantDefinition>,
+}
+
+impl MerchantDatabase {
+    pub fn get(&self, merchant_id: &str) -> Option<&MerchantDefinition> {
+        self.merchants.get(merchant_id)
+    }
+}
+
+// 상인별 한정 상품 구매 수 - 재시작해도 다시 채워지지 않도록 디스크에 저장
+#[derive(Resource, Default, Serialize, Deserialize)]
+pub struct PurchasedStock {
+    pub merchants: BTreeMap<String, BTreeMap<String, u32>>,
+}
+
+impl PurchasedStock {
+    // 남은 수량 (무제한 상품은 None)
+    pub fn remaining(&self, merchant_id: &str, entry: &StockEntry) -> Option<u32> {
+        let bought = self
+            .merchants
+            .get(merchant_id)
+            .and_then(|items| items.get(&entry.item_id))
+            .copied()
+            .unwrap_or(0);
+        entry.quantity.map(|quantity| quantity.saturating_sub(bought))
+    }
+
+    pub fn record(&mut self, merchant_id: &str, item_id: &str) {
+        *self
+            .merchants
+            .entry(merchant_id.to_string())
+            .or_default()
+            .entry(item_id.to_string())
+            .or_default() += 1;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShopTab {
+    #[default]
+    Buy,
+    Sell,
+}
+
+// 상점 메뉴 - 대화 중인 상인과 선택한 탭 / 줄
+#[derive(Resource, Default)]
+pub struct ShopMenuState {
+    pub merchant_id: String,
+    pub tab: ShopTab,
+    pub selected: usize,
+}
@@ -1,3 +1,23 @@
+pub mod animation;
+pub mod boss;
+pub mod checkpoint;
 pub mod input;
+pub mod item;
+pub mod loot;
+pub mod merchant;
+pub mod moveset;
+pub mod noise;
+pub mod progress;
+pub mod resistance;
 
-pub use input::*;
\ No newline at end of file
+pub use animation::*;
+pub use boss::*;
+pub use checkpoint::*;
+pub use input::*;
+pub use item::*;
+pub use loot::*;
+pub use merchant::*;
+pub use moveset::*;
+pub use noise::*;
+pub use progress::*;
+pub use resistance::*;
@@ -0,0 +1,150 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// 기획자가 수정하는 무기별 모션 데이터 파일
+pub const MOVESET_DATA_PATH: &str = "assets/metadata/movesets.json";
+
+// poise_damage 가 없는 모션 데이터용 기본 강인도 감소량 (single_swing 과 같은 값)
+fn default_swing_poise_damage() -> f32 {
+    10.0
+}
+
+fn default_heavy_poise_damage() -> f32 {
+    20.0
+}
+
+// 약공격 콤보의 한 동작
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SwingDefinition {
+    pub animation: String,       // animation_mapping.json 키 (attack_1, attack_2 ...)
+    pub damage_multiplier: f32,  // Weapon.damage 배율
+    pub stamina_cost: f32,
+    pub duration: f32,           // 동작이 끝날 때까지 걸리는 시간
+    pub combo_window: f32,       // 동작 종료 후 다음 타로 이어지는 입력 허용 시간
+    #[serde(default = "default_swing_poise_damage")]
+    pub poise_damage: f32,       // 상대 강인도 감소량
+    #[serde(default)]
+    pub hyper_armor: bool,       // 동작 중 강인도가 깎여도 경직되지 않음
+}
+
+// 우클릭 차지 강공격
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct HeavyAttackDefinition {
+    pub animation: String,
+    pub damage_multiplier: f32,          // 차지 없이 바로 뗐을 때
+    pub charged_damage_multiplier: f32,  // 풀 차지 시
+    pub stamina_cost: f32,
+    pub charge_time: f32,                // 풀 차지까지 걸리는 시간 (도달하면 자동 발동)
+    pub duration: f32,
+    #[serde(default = "default_heavy_poise_damage")]
+    pub poise_damage: f32,
+    #[serde(default)]
+    pub hyper_armor: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Moveset {
+    pub light_chain: Vec<SwingDefinition>,
+    pub heavy: HeavyAttackDefinition,
+}
+
+impl Moveset {
+    // 콤보가 비어 있거나 차지 시간이 0 이하면 공격 처리가 불가능하므로 거부
+    pub fn validate(&self) -> Result<(), String> {
+        if self.light_chain.is_empty() {
+            return Err("light_chain is empty".to_string());
+        }
+        if self.heavy.charge_time <= 0.0 {
+            return Err(format!("heavy.charge_time must be positive (got {})", self.heavy.charge_time));
+        }
+        Ok(())
+    }
+
+    // 모션 데이터가 없는 무기를 위한 단일 공격 모션
+    pub fn single_swing(damage_multiplier: f32, stamina_cost: f32, duration: f32) -> Self {
+        Self {
+            light_chain: vec![SwingDefinition {
+                animation: "attack_1".to_string(),
+                damage_multiplier,
+                stamina_cost,
+                duration,
+                combo_window: 0.0,
+                poise_damage: 10.0,
+                hyper_armor: false,
+            }],
+            heavy: HeavyAttackDefinition {
+                animation: "attack_heavy".to_string(),
+                damage_multiplier: damage_multiplier * 1.5,
+                charged_damage_multiplier: damage_multiplier * 2.0,
+                stamina_cost: stamina_cost * 1.5,
+                charge_time: 1.0,
+                duration: duration * 1.4,
+                poise_damage: 20.0,
+                hyper_armor: true,
+            },
+        }
+    }
+}
+
+#[derive(Resource)]
+pub struct MovesetDatabase {
+    pub movesets: HashMap<String, Moveset>,
+}
+
+impl Default for MovesetDatabase {
+    fn default() -> Self {
+        let mut movesets = HashMap::new();
+
+        // 파일을 읽지 못했을 때 사용하는 기본 한손검 모션
+        movesets.insert("straight_sword".to_string(), Moveset {
+            light_chain: vec![
+                SwingDefinition {
+                    animation: "attack_1".to_string(),
+                    damage_multiplier: 1.0,
+                    stamina_cost: 15.0,
+                    duration: 0.6,
+                    combo_window: 0.4,
+                    poise_damage: 10.0,
+                    hyper_armor: false,
+                },
+                SwingDefinition {
+                    animation: "attack_2".to_string(),
+                    damage_multiplier: 1.1,
+                    stamina_cost: 15.0,
+                    duration: 0.6,
+                    combo_window: 0.4,
+                    poise_damage: 10.0,
+                    hyper_armor: false,
+                },
+                SwingDefinition {
+                    animation: "attack_3".to_string(),
+                    damage_multiplier: 1.35,
+                    stamina_cost: 20.0,
+                    duration: 0.8,
+                    combo_window: 0.0,
+                    poise_damage: 14.0,
+                    hyper_armor: false,
+                },
+            ],
+            heavy: HeavyAttackDefinition {
+                animation: "attack_heavy".to_string(),
+                damage_multiplier: 1.5,
+                charged_damage_multiplier: 2.2,
+                stamina_cost: 30.0,
+                charge_time: 1.0,
+                duration: 1.0,
+                poise_damage: 25.0,
+                hyper_armor: true,
+            },
+        });
+
+        Self { movesets }
+    }
+}
+
+impl MovesetDatabase {
+    pub fn get(&self, moveset_id: &str) -> Option<&Moveset> {
+        self.movesets.get(moveset_id)
+    }
+}
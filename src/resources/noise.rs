@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+// 소음 함정 구역 맵 데이터 파일
+pub const SOUND_TRAP_DATA_PATH: &str = "assets/metadata/sound_traps.json";
+
+// 소음 함정 구역 - min.y 를 바닥 높이로 보고 그 위에 바닥 장식을 깔아 둠
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SoundTrapArea {
+    pub id: String,
+    pub min: [f32; 3],
+    pub max: [f32; 3],
+    pub sound_radius: f32,
+    #[serde(default)]
+    pub ground_cover_density: f32,  // 제곱미터당 바닥 장식 (수정 파편) 개수
+}
+
+#[derive(Resource, Default)]
+pub struct SoundTrapDatabase {
+    pub areas: Vec<SoundTrapArea>,
+}
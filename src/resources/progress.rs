@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use crate::components::{CharacterStats, Equipment, WeaponCondition};
+
+// 플레이어 진행 상황 저장 파일 - 획득 목록 / 상인 재고 저장과 짝을 이룸
+pub const PLAYER_PROGRESS_SAVE_PATH: &str = "saves/player.json";
+
+// 재시작해도 유지되는 플레이어 상태 (소울, 레벨 / 능력치, 소지품, 장비, 무기 강화, 회복 물약 강화)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PlayerProgress {
+    pub souls: i32,
+    #[serde(default)]
+    pub stats: CharacterStats,
+    pub inventory: Vec<(String, u32)>,
+    pub equipment: Equipment,
+    pub weapon_conditions: HashMap<String, WeaponCondition>,
+    pub flask_max_charges: u32,
+    pub flask_potency_level: u32,
+}
+
+// 시작 시 읽어 둔 저장 - 플레이어가 생성되면 적용하고 비움
+#[derive(Resource, Default)]
+pub struct LoadedPlayerProgress(pub Option<PlayerProgress>);
@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+use crate::components::Resistances;
+use crate::systems::world_generation::WorldZone;
+
+// 지역별 적 저항 데이터 파일
+pub const RESISTANCE_DATA_PATH: &str = "assets/metadata/resistances.json";
+
+// 데이터에 없는 지역은 기본 저항(감소율 0, 기본 임계치) 사용
+#[derive(Resource, Default)]
+pub struct ResistanceDatabase {
+    pub zones: HashMap<WorldZone, Resistances>,
+}
+
+impl ResistanceDatabase {
+    pub fn for_zone(&self, zone: WorldZone) -> Resistances {
+        self.zones.get(&zone).cloned().unwrap_or_default()
+    }
+}
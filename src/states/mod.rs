@@ -7,5 +7,10 @@ pub enum GameState {
     Playing,
     Paused,
     Death,
+    FastTravel,  // 체크포인트 이동 메뉴
+    LevelUp,     // 체크포인트 레벨업 메뉴
+    Inventory,   // 장비 / 소지품 메뉴
+    Smithing,    // 체크포인트 무기 강화 메뉴
+    Shop,        // 상인 구매 / 판매 메뉴
     Loading,
 }
\ No newline at end of file
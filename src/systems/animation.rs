@@ -1,16 +1,18 @@
 use bevy::prelude::*;
 use bevy_rapier3d::prelude::*;
 use crate::components::{
-    player::{Player, AnimationState, AnimationController, AnimationClips},
-    combat::AttackState,
-    stats::{Health, Stamina},
+    player::{Player, AnimationState, AnimationController, AnimationClips, LockOnState, RollDirection},
+    combat::{AttackState, GuardState, ParryState, CriticalAttackState, Weapon},
+    stats::{Health, Poise},
+    item::HealingFlask,
 };
-use crate::resources::InputMapping;
+use std::collections::HashMap;
+use crate::resources::{AnimationMapping, InputMapping, ANIMATION_MAPPING_PATH};
+use crate::systems::combat::GUARD_BREAK_DURATION;
+use crate::utils::math::facing_direction;
 
 // 애니메이션 지속 시간
-const ROLL_DURATION: f32 = 0.7;
 const SLASH_DURATION: f32 = 0.8;
-const PARRY_DURATION: f32 = 0.5;
 const JUMP_DURATION: f32 = 1.0;
 const DEATH_DURATION: f32 = 2.0;
 
@@ -18,18 +20,23 @@ const DEATH_DURATION: f32 = 2.0;
 pub fn update_animation_state(
     mut player_query: Query<(
         &mut AnimationController,
+        &Transform,
         &Velocity,
         &Player,
         &Health,
-        &Stamina,
+        &Poise,
         &AttackState,
+        &GuardState,
+        &ParryState,
+        &CriticalAttackState,
+        &LockOnState,
+        &HealingFlask,
     ), With<Player>>,
     keyboard_input: Res<ButtonInput<KeyCode>>,
-    mouse_input: Res<ButtonInput<MouseButton>>,
     input_mapping: Res<InputMapping>,
     time: Res<Time>,
 ) {
-    for (mut controller, velocity, player, health, stamina, attack_state) in player_query.iter_mut() {
+    for (mut controller, transform, velocity, player, health, poise, attack_state, guard_state, parry_state, critical_state, lock_on, flask) in player_query.iter_mut() {
         let previous_state = controller.current_state;
         
         // 타이머 업데이트
@@ -49,6 +56,38 @@ pub fn update_animation_state(
             continue;
         }
         
+        // 앞잡기/뒤잡기 (끊을 수 없는 연출)
+        if critical_state.is_active() {
+            if controller.current_state != AnimationState::Critical {
+                transition_to_state(&mut controller, AnimationState::Critical, critical_state.timer, false);
+            }
+            continue;
+        }
+        
+        // 가드 브레이크 경직 (다른 동작을 끊고 재생)
+        if guard_state.is_guard_broken() {
+            if controller.current_state != AnimationState::GuardBreak {
+                transition_to_state(&mut controller, AnimationState::GuardBreak, GUARD_BREAK_DURATION, false);
+            }
+            continue;
+        }
+        
+        // 강인도 경직 - 진행 중인 동작을 끊고 피격 모션 재생
+        if poise.is_staggered() {
+            if controller.current_state != AnimationState::HitReact {
+                transition_to_state(&mut controller, AnimationState::HitReact, poise.stagger_timer, false);
+            }
+            continue;
+        }
+        
+        // 물약 마시기 - 천천히 걸을 수는 있지만 다른 동작으로 바뀌지 않음
+        if flask.is_drinking() {
+            if controller.current_state != AnimationState::Drink {
+                transition_to_state(&mut controller, AnimationState::Drink, 0.0, true);
+            }
+            continue;
+        }
+        
         // 인터럽트 불가능한 애니메이션 중이면 스킵
         if !controller.can_interrupt && !controller.transition_timer.finished() {
             continue;
@@ -56,24 +95,48 @@ pub fn update_animation_state(
         
         // 구르기 (회피)
         if player.is_rolling {
-            if controller.current_state != AnimationState::Roll {
-                transition_to_state(&mut controller, AnimationState::Roll, ROLL_DURATION, false);
+            // 실제 구르기 방향에 맞는 애니메이션 선택
+            let roll_state = player.roll_direction.animation_state();
+            if controller.current_state != roll_state {
+                transition_to_state(&mut controller, roll_state, player.current_roll_duration, false);
+            }
+            continue;
+        }
+        
+        // 패링 (Shift + 우클릭) - parry_system 이 시작한 동작을 재생
+        if parry_state.is_parrying() {
+            if controller.current_state != AnimationState::Parry {
+                transition_to_state(&mut controller, AnimationState::Parry, parry_state.timer, false);
             }
             continue;
         }
         
-        // 패링 (Shift + 우클릭)
-        if keyboard_input.pressed(input_mapping.block) && mouse_input.just_pressed(MouseButton::Right) {
-            if stamina.current >= 10.0 && controller.current_state != AnimationState::Parry {
-                transition_to_state(&mut controller, AnimationState::Parry, PARRY_DURATION, false);
+        // 강공격 차지 (우클릭 유지)
+        if attack_state.is_charging {
+            if controller.current_state != AnimationState::HeavyCharge {
+                transition_to_state(&mut controller, AnimationState::HeavyCharge, 0.0, true);
             }
             continue;
         }
         
-        // 공격 (좌클릭)
+        // 공격 (좌클릭 콤보 / 강공격) - 동작 시간만큼 재생
         if attack_state.is_attacking {
-            if controller.current_state != AnimationState::Slash {
-                transition_to_state(&mut controller, AnimationState::Slash, SLASH_DURATION, false);
+            let attack_animation = attack_state.current_animation;
+            if controller.current_state != attack_animation {
+                let duration = if attack_state.attack_timer > 0.0 {
+                    attack_state.attack_timer
+                } else {
+                    SLASH_DURATION
+                };
+                transition_to_state(&mut controller, attack_animation, duration, false);
+            }
+            continue;
+        }
+        
+        // 가드 (Shift 유지)
+        if guard_state.is_guarding {
+            if controller.current_state != AnimationState::Guard {
+                transition_to_state(&mut controller, AnimationState::Guard, 0.0, true);
             }
             continue;
         }
@@ -91,14 +154,25 @@ pub fn update_animation_state(
         
         if horizontal_speed > 0.1 {
             // 달리기 (Shift + 이동)
-            if keyboard_input.pressed(KeyCode::ShiftLeft) && horizontal_speed > 3.0 {
+            if keyboard_input.pressed(input_mapping.sprint) && horizontal_speed > 3.0 {
                 if controller.current_state != AnimationState::Run {
                     transition_to_state(&mut controller, AnimationState::Run, 0.0, true);
                 }
             } else {
-                // 걷기
-                if controller.current_state != AnimationState::Walk {
-                    transition_to_state(&mut controller, AnimationState::Walk, 0.0, true);
+                // 걷기 (락온 중에는 대상 기준 옆걸음/뒷걸음)
+                let walk_state = if lock_on.is_locked() {
+                    let move_dir = Vec3::new(velocity.linvel.x, 0.0, velocity.linvel.z);
+                    match RollDirection::from_vectors(facing_direction(transform), move_dir) {
+                        RollDirection::Backward => AnimationState::WalkBackward,
+                        RollDirection::Left => AnimationState::StrafeLeft,
+                        RollDirection::Right => AnimationState::StrafeRight,
+                        _ => AnimationState::Walk,
+                    }
+                } else {
+                    AnimationState::Walk
+                };
+                if controller.current_state != walk_state {
+                    transition_to_state(&mut controller, walk_state, 0.0, true);
                 }
             }
         } else {
@@ -151,9 +225,25 @@ pub fn play_animations(
             AnimationState::Idle => clips.idle.clone(),
             AnimationState::Walk => clips.walk.clone(),
             AnimationState::Run => clips.run.clone(),
+            AnimationState::WalkBackward => clips.walk_backward.clone(),
+            AnimationState::StrafeLeft => clips.strafe_left.clone(),
+            AnimationState::StrafeRight => clips.strafe_right.clone(),
             AnimationState::Roll => clips.roll.clone(),
+            AnimationState::RollBackward => clips.roll_backward.clone(),
+            AnimationState::RollLeft => clips.roll_left.clone(),
+            AnimationState::RollRight => clips.roll_right.clone(),
+            AnimationState::Backstep => clips.backstep.clone(),
             AnimationState::Jump => clips.jump.clone(),
             AnimationState::Slash => clips.slash.clone(),
+            AnimationState::Slash2 => clips.slash_2.clone(),
+            AnimationState::Slash3 => clips.slash_3.clone(),
+            AnimationState::HeavyCharge => clips.heavy_charge.clone(),
+            AnimationState::HeavySlash => clips.heavy_slash.clone(),
+            AnimationState::Guard => clips.guard.clone(),
+            AnimationState::GuardBreak => clips.guard_break.clone(),
+            AnimationState::HitReact => clips.hit_react.clone(),
+            AnimationState::Drink => clips.drink.clone(),
+            AnimationState::Critical => clips.critical.clone(),
             AnimationState::Parry => clips.parry.clone(),
             AnimationState::Death => clips.death.clone(),
         };
@@ -173,7 +263,9 @@ pub fn play_animations(
                     // 애니메이션 재생 설정
                     match controller.current_state {
                         // 반복 애니메이션
-                        AnimationState::Idle | AnimationState::Walk | AnimationState::Run => {
+                        AnimationState::Idle | AnimationState::Walk | AnimationState::Run
+                        | AnimationState::WalkBackward | AnimationState::StrafeLeft | AnimationState::StrafeRight
+                        | AnimationState::HeavyCharge | AnimationState::Guard => {
                             animation_player.play(node_index).repeat();
                         },
                         // 한 번만 재생
@@ -214,30 +306,127 @@ fn find_animation_player_recursive(
     None
 }
 
-// Aurora 애니메이션 로드
-pub fn load_aurora_animations(
-    mut commands: Commands,
+pub fn load_animation_mapping(mut mapping: ResMut<AnimationMapping>) {
+    match std::fs::read_to_string(ANIMATION_MAPPING_PATH) {
+        Ok(json) => match serde_json::from_str::<HashMap<String, HashMap<String, String>>>(&json) {
+            Ok(sets) => {
+                info!("Loaded {} animation sets from {}", sets.len(), ANIMATION_MAPPING_PATH);
+                mapping.sets.extend(sets);
+            }
+            Err(e) => error!("Failed to parse animation mapping JSON: {}", e),
+        },
+        Err(e) => warn!("Failed to read animation mapping file, using Aurora animations only: {}", e),
+    }
+}
+
+// Aurora 기본 애니메이션 - 다른 세트에 없는 동작은 이 클립을 그대로 사용
+fn aurora_clips(asset_server: &AssetServer) -> AnimationClips {
+    AnimationClips {
+        animation_set: "Aurora".to_string(),
+        idle: Some(asset_server.load("Animations_GLTF/Aurora/Idle.glb#Animation0")),                       // 대기
+        walk: Some(asset_server.load("Animations_GLTF/Aurora/Jog_Fwd_Combat.glb#Animation0")),            // 걷기
+        run: Some(asset_server.load("Animations_GLTF/Aurora/Jog_Fwd.glb#Animation0")),                    // 달리기
+        walk_backward: Some(asset_server.load("Animations_GLTF/Aurora/Jog_Bwd.glb#Animation0")),          // 락온 뒷걸음
+        strafe_left: Some(asset_server.load("Animations_GLTF/Aurora/Jog_Left.glb#Animation0")),           // 락온 왼쪽 옆걸음
+        strafe_right: Some(asset_server.load("Animations_GLTF/Aurora/Jog_Right.glb#Animation0")),         // 락온 오른쪽 옆걸음
+        roll: Some(asset_server.load("Animations_GLTF/Aurora/Ability_RMB_Fwd.glb#Animation0")),           // 회피 (앞)
+        roll_backward: Some(asset_server.load("Animations_GLTF/Aurora/Ability_RMB_Bwd.glb#Animation0")),  // 회피 (뒤)
+        roll_left: Some(asset_server.load("Animations_GLTF/Aurora/Ability_RMB_Left.glb#Animation0")),     // 회피 (왼쪽)
+        roll_right: Some(asset_server.load("Animations_GLTF/Aurora/Ability_RMB_Right.glb#Animation0")),   // 회피 (오른쪽)
+        backstep: Some(asset_server.load("Animations_GLTF/Aurora/Knockback_Bwd.glb#Animation0")),        // 백스텝
+        jump: Some(asset_server.load("Animations_GLTF/Aurora/Jump_Start.glb#Animation0")),                // 점프
+        slash: Some(asset_server.load("Animations_GLTF/Aurora/Primary_Attack_A.glb#Animation0")),         // 공격 1타
+        slash_2: Some(asset_server.load("Animations_GLTF/Aurora/Primary_Attack_B.glb#Animation0")),       // 공격 2타
+        slash_3: Some(asset_server.load("Animations_GLTF/Aurora/Primary_Attack_C.glb#Animation0")),       // 공격 3타
+        heavy_charge: Some(asset_server.load("Animations_GLTF/Aurora/Ability_Q_Targeting.glb#Animation0")), // 강공격 차지
+        heavy_slash: Some(asset_server.load("Animations_GLTF/Aurora/Primary_Attack_Slow_A.glb#Animation0")), // 강공격
+        guard: Some(asset_server.load("Animations_GLTF/Aurora/Idle_Straight.glb#Animation0")),           // 가드
+        guard_break: Some(asset_server.load("Animations_GLTF/Aurora/Stun_Start.glb#Animation0")),        // 가드 브레이크
+        hit_react: Some(asset_server.load("Animations_GLTF/Aurora/HitReact_Front.glb#Animation0")),       // 강인도 경직
+        drink: Some(asset_server.load("Animations_GLTF/Aurora/Recall.glb#Animation0")),                   // 물약 마시기
+        critical: Some(asset_server.load("Animations_GLTF/Aurora/Primary_Attack_Slow_B.glb#Animation0")),  // 앞잡기/뒤잡기
+        parry: Some(asset_server.load("Animations_GLTF/Aurora/HitReact_Front.glb#Animation0")),           // 패링
+        death: Some(asset_server.load("Animations_GLTF/Aurora/Death.glb#Animation0")),                    // 사망
+    }
+}
+
+// animation_mapping.json 키 → 클립 칸
+fn clip_slot<'a>(clips: &'a mut AnimationClips, key: &str) -> Option<&'a mut Option<Handle<AnimationClip>>> {
+    let slot = match key {
+        "idle" => &mut clips.idle,
+        "walk" => &mut clips.walk,
+        "run" => &mut clips.run,
+        "walk_backward" => &mut clips.walk_backward,
+        "strafe_left" => &mut clips.strafe_left,
+        "strafe_right" => &mut clips.strafe_right,
+        "roll_forward" => &mut clips.roll,
+        "roll_backward" => &mut clips.roll_backward,
+        "roll_left" => &mut clips.roll_left,
+        "roll_right" => &mut clips.roll_right,
+        "backstep" => &mut clips.backstep,
+        "jump_start" => &mut clips.jump,
+        "attack_1" | "slash" => &mut clips.slash,
+        "attack_2" => &mut clips.slash_2,
+        "attack_3" => &mut clips.slash_3,
+        "attack_heavy_charge" => &mut clips.heavy_charge,
+        "attack_heavy" => &mut clips.heavy_slash,
+        "guard" => &mut clips.guard,
+        "guard_break" => &mut clips.guard_break,
+        "hit_react" => &mut clips.hit_react,
+        "drink" => &mut clips.drink,
+        "critical" => &mut clips.critical,
+        "parry" => &mut clips.parry,
+        "death" => &mut clips.death,
+        _ => return None,
+    };
+    Some(slot)
+}
+
+// 든 무기의 애니메이션 세트로 클립 교체 (Aurora 기본 클립 위에 세트에 있는 동작만 덮어씀)
+pub fn load_weapon_animations(
     asset_server: Res<AssetServer>,
-    player_query: Query<Entity, (With<Player>, Without<AnimationClips>)>,
+    mapping: Res<AnimationMapping>,
+    mut player_query: Query<(Entity, &Weapon, &mut AnimationClips, &mut AnimationController), With<Player>>,
 ) {
-    for player_entity in player_query.iter() {
-        // 변환된 GLTF 애니메이션 파일 사용
-        let clips = AnimationClips {
-            idle: Some(asset_server.load("Animations_GLTF/Aurora/Idle.glb#Animation0")),                       // 대기
-            walk: Some(asset_server.load("Animations_GLTF/Aurora/Jog_Fwd_Combat.glb#Animation0")),            // 걷기
-            run: Some(asset_server.load("Animations_GLTF/Aurora/Jog_Fwd.glb#Animation0")),                    // 달리기
-            roll: Some(asset_server.load("Animations_GLTF/Aurora/Ability_RMB_Fwd.glb#Animation0")),           // 회피
-            jump: Some(asset_server.load("Animations_GLTF/Aurora/Jump_Start.glb#Animation0")),                // 점프
-            slash: Some(asset_server.load("Animations_GLTF/Aurora/Primary_Attack_A.glb#Animation0")),         // 공격
-            parry: Some(asset_server.load("Animations_GLTF/Aurora/HitReact_Front.glb#Animation0")),           // 패링
-            death: Some(asset_server.load("Animations_GLTF/Aurora/Death.glb#Animation0")),                    // 사망
-        };
-        
-        commands.entity(player_entity)
-            .insert(clips)
-            .insert(AnimationController::default());
-            
-        info!("Aurora animations loaded for player entity: {:?}", player_entity);
+    for (player_entity, weapon, mut clips, mut controller) in player_query.iter_mut() {
+        if clips.animation_set == weapon.animation_set {
+            continue;
+        }
+
+        let mut new_clips = aurora_clips(&asset_server);
+        new_clips.animation_set = weapon.animation_set.clone();
+
+        if weapon.animation_set != "Aurora" {
+            match mapping.get(&weapon.animation_set) {
+                Some(set) => {
+                    for (key, file) in set {
+                        let path = format!("Animations_GLTF/{}/{}#Animation0", weapon.animation_set, file);
+                        if let Some(slot) = clip_slot(&mut new_clips, key) {
+                            *slot = Some(asset_server.load(path));
+                        }
+                    }
+
+                    // 콤보 / 강공격 동작이 없는 세트는 기본 베기 동작으로 대신함
+                    if let Some(slash) = set.get("slash").or_else(|| set.get("attack_1")) {
+                        let path = format!("Animations_GLTF/{}/{}#Animation0", weapon.animation_set, slash);
+                        for key in ["attack_2", "attack_3", "attack_heavy"] {
+                            if !set.contains_key(key) {
+                                if let Some(slot) = clip_slot(&mut new_clips, key) {
+                                    *slot = Some(asset_server.load(path.clone()));
+                                }
+                            }
+                        }
+                    }
+                }
+                None => warn!("Animation set '{}' not found, using Aurora animations", weapon.animation_set),
+            }
+        }
+
+        *clips = new_clips;
+        // 현재 동작을 새 세트 클립으로 다시 재생
+        controller.set_changed();
+
+        info!("{} animations loaded for player entity: {:?}", weapon.animation_set, player_entity);
     }
 }
 
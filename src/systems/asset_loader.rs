@@ -80,13 +80,19 @@ pub fn spawn_character_with_sword(
     gltf_assets: Res<Assets<Gltf>>,
     player_query: Query<Entity, With<crate::components::player::Player>>,
     children_query: Query<&Children>,
+    name_query: Query<&Name>,
 ) {
     info!("spawn_character_with_sword called, found {} players", player_query.iter().len());
     
     for player_entity in player_query.iter() {
-        // 이미 모델이 로드되었으면 스킵
-        if children_query.get(player_entity).is_ok() {
-            info!("Player already has children, skipping");
+        // 이미 모델이 로드되었으면 스킵 (손에 든 무기 모델 등 다른 자식은 무시)
+        let has_model = children_query.get(player_entity).is_ok_and(|children| {
+            children
+                .iter()
+                .any(|child| name_query.get(*child).is_ok_and(|name| name.as_str() == "PlayerModel"))
+        });
+        if has_model {
+            info!("Player model already spawned, skipping");
             continue;
         }
         
@@ -122,7 +128,7 @@ pub fn spawn_character_with_sword(
 
                 info!("Aurora character model spawned for player entity {:?}", player_entity);
 
-                // Aurora 모델은 이미 무기를 포함하고 있을 수 있으므로 별도 무기는 추가하지 않음
+                // 무기 / 방패 모델은 장비 시스템(apply_equipment)이 붙임
                 
                 break; // 첫 번째 플레이어만 처리
             } else {
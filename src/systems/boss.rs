@@ -0,0 +1,429 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use rand::prelude::*;
+use std::collections::HashMap;
+use crate::components::*;
+use crate::resources::*;
+use crate::utils::save::{load_save_file, write_save_file};
+use crate::systems::enemy_ai::AttackEffect;
+use crate::systems::projectile::{spawn_projectile, GRAVITY};
+use crate::utils::math::{ballistic_lead_velocity, facing_direction, is_within_facing_angle};
+
+// 이 비율만큼 안쪽으로 들어와야 봉인 (가장자리에 걸친 채로 갇히지 않도록)
+const ARENA_SEAL_RADIUS_RATIO: f32 = 0.85;
+// 페이즈 전환 연출 동안 공격하지 않음
+const PHASE_TRANSITION_DURATION: f32 = 2.0;
+// 이보다 가까우면 다가가지 않음
+const BOSS_STOP_DISTANCE: f32 = 2.0;
+const BOSS_TURN_SPEED: f32 = 4.0;
+const BOSS_PROJECTILE_GRAVITY_SCALE: f32 = 0.3;
+
+pub fn load_boss_database(mut boss_db: ResMut<BossDatabase>) {
+    match std::fs::read_to_string(BOSS_DATA_PATH) {
+        Ok(json) => match serde_json::from_str::<HashMap<String, BossDefinition>>(&json) {
+            Ok(bosses) => {
+                info!("Loaded {} bosses from {}", bosses.len(), BOSS_DATA_PATH);
+                boss_db.bosses.extend(bosses);
+            }
+            Err(e) => error!("Failed to parse boss JSON: {}", e),
+        },
+        Err(e) => warn!("Failed to read boss file, arenas stay empty: {}", e),
+    }
+}
+
+// 저장된 보스 처치 기록 로드 - 파일이 없으면 새 게임
+pub fn load_defeated_bosses(mut defeated: ResMut<DefeatedBosses>) {
+    if let Some(saved) = load_save_file::<DefeatedBosses>(DEFEATED_BOSSES_SAVE_PATH, "defeated bosses") {
+        info!("Loaded {} defeated bosses", saved.ids.len());
+        *defeated = saved;
+    }
+}
+
+// 아레나가 생기면 아직 쓰러뜨리지 않은 보스의 스폰 정의 추가 (실제 생성은 spawn_initial_enemies)
+pub fn init_boss_arenas(
+    mut commands: Commands,
+    boss_db: Res<BossDatabase>,
+    defeated: Res<DefeatedBosses>,
+    arena_query: Query<(&Transform, &BossArena), Added<BossArena>>,
+) {
+    for (transform, arena) in arena_query.iter() {
+        if defeated.ids.contains(&arena.boss_id) {
+            continue;
+        }
+        let Some(definition) = boss_db.get(&arena.boss_id) else {
+            warn!("Boss '{}' not found for arena", arena.boss_id);
+            continue;
+        };
+
+        commands.spawn((
+            EnemySpawnDefinition {
+                position: transform.translation + Vec3::Y * 2.5,
+                rotation: Quat::IDENTITY,
+                enemy: Enemy {
+                    ai_type: EnemyType::Boss,
+                    move_speed: definition.phases.first().map_or(3.0, |phase| phase.move_speed),
+                    soul_reward: definition.soul_reward,
+                    loot_table: definition.loot_table.clone(),
+                    boss_id: Some(arena.boss_id.clone()),
+                    ..default()
+                },
+                max_health: definition.max_health,
+                max_poise: definition.max_poise,
+                zone: arena.zone,
+                patrol: None,
+            },
+            Name::new(format!("BossSpawn_{}", arena.boss_id)),
+        ));
+    }
+}
+
+// 살아 있는 보스의 아레나에 들어서면 봉인하고 전투 시작
+pub fn boss_arena_system(
+    mut encounter_events: EventWriter<BossEncounterStartedEvent>,
+    player_query: Query<(&Transform, &Health), With<Player>>,
+    mut arena_query: Query<(&GlobalTransform, &mut BossArena)>,
+    mut boss_query: Query<&mut BossState, Without<Dead>>,
+) {
+    let Ok((player_transform, health)) = player_query.get_single() else {
+        return;
+    };
+    if health.current <= 0.0 {
+        return;
+    }
+
+    for (arena_transform, mut arena) in arena_query.iter_mut() {
+        if arena.sealed {
+            continue;
+        }
+        let offset = player_transform.translation - arena_transform.translation();
+        let horizontal = Vec2::new(offset.x, offset.z).length();
+        if horizontal > arena.radius * ARENA_SEAL_RADIUS_RATIO {
+            continue;
+        }
+        let Some(mut boss) = boss_query
+            .iter_mut()
+            .find(|boss| boss.boss_id == arena.boss_id)
+        else {
+            continue;
+        };
+
+        arena.sealed = true;
+        boss.active = true;
+        encounter_events.send(BossEncounterStartedEvent {
+            boss_id: arena.boss_id.clone(),
+        });
+        info!("Boss arena sealed: {}", arena.boss_id);
+    }
+}
+
+// 봉인 상태에 맞춰 안개벽을 보이고 막거나, 숨기고 통과시킴
+pub fn fog_wall_system(
+    mut commands: Commands,
+    arena_query: Query<&BossArena, Changed<BossArena>>,
+    mut wall_query: Query<(Entity, &FogWall, &mut Visibility)>,
+) {
+    for arena in arena_query.iter() {
+        for (entity, wall, mut visibility) in wall_query.iter_mut() {
+            if wall.boss_id != arena.boss_id {
+                continue;
+            }
+            if arena.sealed {
+                *visibility = Visibility::Visible;
+                commands.entity(entity).remove::<ColliderDisabled>();
+            } else {
+                *visibility = Visibility::Hidden;
+                commands.entity(entity).insert(ColliderDisabled);
+            }
+        }
+    }
+}
+
+// 보스 행동 - 체력에 따른 페이즈 전환, 페이즈별 공격 중 가중치로 선택 → 예비 동작 → 판정 → 빈틈
+pub fn boss_ai_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    boss_db: Res<BossDatabase>,
+    mut phase_events: EventWriter<BossPhaseChangedEvent>,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut boss_query: Query<
+        (Entity, &mut Transform, &mut BossState, &mut AIState, &Health, &mut Velocity, Option<&mut Poise>),
+        (Without<Dead>, Without<Player>),
+    >,
+    player_query: Query<(Entity, &Transform, &Velocity, &Health), With<Player>>,
+) {
+    let Ok((player_entity, player_transform, player_velocity, player_health)) = player_query.get_single() else {
+        return;
+    };
+    let delta = time.delta_secs();
+    let mut rng = rand::thread_rng();
+
+    for (boss_entity, mut transform, mut boss, mut ai_state, health, mut velocity, poise) in boss_query.iter_mut() {
+        velocity.linvel.x = 0.0;
+        velocity.linvel.z = 0.0;
+        if !boss.active {
+            continue;
+        }
+        let Some(definition) = boss_db.get(&boss.boss_id) else {
+            continue;
+        };
+
+        for cooldown in boss.attack_cooldowns.values_mut() {
+            *cooldown -= delta;
+        }
+
+        // 체력 비율이 기준 이하로 떨어진 가장 늦은 페이즈로 전환 (한 번에 여러 단계를 건너뛸 수 있음)
+        let health_ratio = health.current / health.max;
+        let target_phase = definition
+            .phases
+            .iter()
+            .rposition(|phase| health_ratio <= phase.health_threshold)
+            .unwrap_or(0);
+        if target_phase > boss.phase {
+            boss.phase = target_phase;
+            boss.current_attack = None;
+            boss.action_timer = PHASE_TRANSITION_DURATION;
+            phase_events.send(BossPhaseChangedEvent {
+                boss_id: boss.boss_id.clone(),
+                phase: target_phase,
+            });
+            info!("{} enters phase {}", definition.name, target_phase + 1);
+        }
+
+        // 패링 / 강인도 경직 중에는 예비 동작이 끊김
+        if matches!(ai_state.current_state, AIStateType::Stunned | AIStateType::Staggered) {
+            ai_state.state_timer -= delta;
+            boss.current_attack = None;
+            if ai_state.state_timer > 0.0 {
+                continue;
+            }
+            ai_state.current_state = AIStateType::Chase;
+        }
+
+        // 예비 동작 중에는 맞아도 경직되지 않음
+        if let Some(mut poise) = poise {
+            poise.hyper_armor = boss.current_attack.is_some();
+        }
+
+        let to_player = player_transform.translation - transform.translation;
+        let flat_direction = Vec3::new(to_player.x, 0.0, to_player.z).normalize_or_zero();
+        let distance = to_player.length();
+        if flat_direction != Vec3::ZERO {
+            let target_rotation = Quat::from_rotation_arc(Vec3::Z, flat_direction);
+            transform.rotation = transform.rotation.slerp(target_rotation, (BOSS_TURN_SPEED * delta).min(1.0));
+        }
+
+        if boss.action_timer > 0.0 {
+            boss.action_timer -= delta;
+            if boss.action_timer <= 0.0 {
+                if let Some(attack) = boss.current_attack.take() {
+                    perform_boss_attack(
+                        &mut commands,
+                        &mut meshes,
+                        &mut materials,
+                        &mut damage_events,
+                        boss_entity,
+                        &transform,
+                        &attack,
+                        player_entity,
+                        player_transform,
+                        player_velocity.linvel,
+                    );
+                    boss.action_timer = attack.recovery;
+                }
+            }
+            continue;
+        }
+
+        if player_health.current <= 0.0 {
+            continue;
+        }
+
+        let Some(phase) = definition.phases.get(boss.phase) else {
+            continue;
+        };
+        let available: Vec<&BossAttack> = phase
+            .attacks
+            .iter()
+            .filter(|attack| boss.attack_cooldowns.get(&attack.name).map_or(true, |cooldown| *cooldown <= 0.0))
+            .filter(|attack| distance <= attack.kind.reach())
+            .collect();
+
+        if let Ok(attack) = available.choose_weighted(&mut rng, |attack| attack.weight) {
+            boss.attack_cooldowns.insert(attack.name.clone(), attack.cooldown);
+            boss.current_attack = Some((*attack).clone());
+            boss.action_timer = attack.windup;
+            info!("{} readies {}", definition.name, attack.name);
+        } else if distance > BOSS_STOP_DISTANCE {
+            velocity.linvel.x = flat_direction.x * phase.move_speed;
+            velocity.linvel.z = flat_direction.z * phase.move_speed;
+        }
+    }
+}
+
+fn perform_boss_attack(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    damage_events: &mut EventWriter<DamageEvent>,
+    boss_entity: Entity,
+    boss_transform: &Transform,
+    attack: &BossAttack,
+    player_entity: Entity,
+    player_transform: &Transform,
+    player_velocity: Vec3,
+) {
+    let damage = DamagePacket::single(attack.damage_type, attack.damage);
+    let distance = boss_transform.translation.distance(player_transform.translation);
+    let hit = match &attack.kind {
+        BossAttackKind::Melee { range, angle } => {
+            distance <= *range && is_within_facing_angle(boss_transform, player_transform.translation, *angle)
+        }
+        BossAttackKind::Area { radius } => {
+            // 충격파 표시 (바닥 원판)
+            commands.spawn((
+                Mesh3d(meshes.add(Cylinder::new(*radius, 0.1))),
+                MeshMaterial3d(materials.add(StandardMaterial {
+                    base_color: Color::srgba(1.0, 0.4, 0.2, 0.4),
+                    alpha_mode: AlphaMode::Blend,
+                    ..default()
+                })),
+                Transform::from_translation(boss_transform.translation - Vec3::Y * 1.5),
+                AttackEffect { lifetime: 0.3 },
+            ));
+            distance <= *radius
+        }
+        BossAttackKind::Volley { count, spread, speed, .. } => {
+            let origin = boss_transform.translation + Vec3::Y * 1.5 + facing_direction(boss_transform) * 1.5;
+            let aim = ballistic_lead_velocity(
+                origin,
+                player_transform.translation + Vec3::Y * 0.5,
+                player_velocity * Vec3::new(1.0, 0.0, 1.0),
+                *speed,
+                GRAVITY * BOSS_PROJECTILE_GRAVITY_SCALE,
+            );
+            // 가운데 한 발은 예측 조준, 나머지는 좌우로 고르게 펼침
+            for index in 0..*count {
+                let t = if *count > 1 { index as f32 / (*count - 1) as f32 - 0.5 } else { 0.0 };
+                let velocity = Quat::from_rotation_y(t * spread) * aim;
+                spawn_projectile(
+                    commands,
+                    meshes,
+                    materials,
+                    Projectile {
+                        owner: boss_entity,
+                        damage: damage.clone(),
+                        poise_damage: attack.poise_damage,
+                        gravity_scale: BOSS_PROJECTILE_GRAVITY_SCALE,
+                        stick_on_impact: false,
+                        ..default()
+                    },
+                    origin,
+                    velocity,
+                );
+            }
+            false
+        }
+    };
+
+    if hit {
+        // 충격파는 패링/가드로 막을 수 없음 - 구르기로만 피함
+        let source = match attack.kind {
+            BossAttackKind::Area { .. } => DamageSource::Area,
+            _ => DamageSource::Melee,
+        };
+        damage_events.send(DamageEvent {
+            attacker: boss_entity,
+            victim: player_entity,
+            damage,
+            poise_damage: attack.poise_damage,
+            hit_position: player_transform.translation + Vec3::Y * 1.0,
+            source,
+        });
+    }
+}
+
+// 페이즈 전환 시 해당 페이즈까지의 아레나 지형을 드러냄
+pub fn arena_phase_element_system(
+    mut commands: Commands,
+    mut phase_events: EventReader<BossPhaseChangedEvent>,
+    mut element_query: Query<(Entity, &ArenaPhaseElement, &mut Visibility)>,
+) {
+    for event in phase_events.read() {
+        for (entity, element, mut visibility) in element_query.iter_mut() {
+            if element.boss_id == event.boss_id && element.phase <= event.phase {
+                *visibility = Visibility::Visible;
+                commands.entity(entity).remove::<ColliderDisabled>();
+            }
+        }
+    }
+}
+
+// 보스 처치 - 기록 저장, 안개벽 해제
+pub fn boss_defeat_system(
+    mut death_events: EventReader<DeathEvent>,
+    mut defeated_events: EventWriter<BossDefeatedEvent>,
+    mut defeated: ResMut<DefeatedBosses>,
+    boss_db: Res<BossDatabase>,
+    boss_query: Query<&BossState>,
+    mut arena_query: Query<&mut BossArena>,
+) {
+    for event in death_events.read() {
+        let Ok(boss) = boss_query.get(event.entity) else {
+            continue;
+        };
+
+        defeated.ids.insert(boss.boss_id.clone());
+        write_save_file(DEFEATED_BOSSES_SAVE_PATH, "defeated bosses", &*defeated);
+        for mut arena in arena_query.iter_mut() {
+            if arena.boss_id == boss.boss_id {
+                arena.sealed = false;
+            }
+        }
+        defeated_events.send(BossDefeatedEvent {
+            boss_id: boss.boss_id.clone(),
+        });
+        info!("Boss defeated: {}", boss_db.get(&boss.boss_id).map_or(boss.boss_id.as_str(), |boss| boss.name.as_str()));
+    }
+}
+
+// 플레이어가 죽거나 휴식하면 보스전 초기화 - 보스는 제자리로 돌아가 체력 회복, 아레나 봉인 해제
+pub fn boss_reset_system(
+    mut commands: Commands,
+    mut reset_events: EventReader<WorldResetEvent>,
+    mut boss_query: Query<
+        (Entity, &mut BossState, &mut AIState, &mut Transform, &mut Velocity, &mut Health, Option<&mut Poise>),
+        Without<Dead>,
+    >,
+    mut arena_query: Query<&mut BossArena>,
+    mut element_query: Query<(Entity, &mut Visibility), With<ArenaPhaseElement>>,
+) {
+    if reset_events.read().count() == 0 {
+        return;
+    }
+
+    for (entity, mut boss, mut ai_state, mut transform, mut velocity, mut health, poise) in boss_query.iter_mut() {
+        boss.reset();
+        *ai_state = AIState::default();
+        transform.translation = boss.home;
+        velocity.linvel = Vec3::ZERO;
+        health.current = health.max;
+        if let Some(mut poise) = poise {
+            poise.current = poise.max;
+            poise.hyper_armor = false;
+        }
+        commands.entity(entity).insert(StatusBuildup::default());
+    }
+
+    for mut arena in arena_query.iter_mut() {
+        if arena.sealed {
+            arena.sealed = false;
+        }
+    }
+
+    for (entity, mut visibility) in element_query.iter_mut() {
+        *visibility = Visibility::Hidden;
+        commands.entity(entity).insert(ColliderDisabled);
+    }
+}
@@ -0,0 +1,157 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use crate::components::*;
+use crate::resources::*;
+use crate::utils::save::{load_save_file, write_save_file};
+use crate::systems::loot::is_loot_in_reach;
+use crate::systems::world_generation::{WorldObject, WorldZone};
+
+// 이 거리 안에서 상호작용 키를 누르면 휴식
+pub const CHECKPOINT_INTERACT_RANGE: f32 = 3.0;
+// 체크포인트 앞 부활 위치 (체크포인트 기준)
+pub const CHECKPOINT_RESPAWN_OFFSET: Vec3 = Vec3::new(0.0, 0.5, 2.0);
+const CHECKPOINT_LIT_INTENSITY: f32 = 5000.0;
+const CHECKPOINT_UNLIT_INTENSITY: f32 = 500.0;
+
+// 저장된 점화 체크포인트 목록 로드 - 파일이 없으면 새 게임
+pub fn load_lit_checkpoints(mut lit_checkpoints: ResMut<LitCheckpoints>) {
+    if let Some(saved) = load_save_file::<LitCheckpoints>(LIT_CHECKPOINTS_SAVE_PATH, "lit checkpoint") {
+        info!("Loaded {} lit checkpoints", saved.ids.len());
+        *lit_checkpoints = saved;
+    }
+}
+
+// CheckpointMarker 가 붙은 엔티티를 휴식 가능한 체크포인트로 등록
+pub fn init_checkpoints(
+    mut commands: Commands,
+    lit_checkpoints: Res<LitCheckpoints>,
+    marker_query: Query<(Entity, &Transform, Option<&Name>, Option<&WorldObject>), (Added<CheckpointMarker>, Without<Checkpoint>)>,
+) {
+    for (entity, transform, name, world_object) in marker_query.iter() {
+        let id = match name {
+            Some(name) => name.as_str().to_string(),
+            None => format!(
+                "checkpoint_{:.0}_{:.0}_{:.0}",
+                transform.translation.x, transform.translation.y, transform.translation.z
+            ),
+        };
+        let zone = world_object.map_or(WorldZone::PilgrimsAscent, |object| object.zone);
+
+        let lit = lit_checkpoints.ids.contains(&id);
+
+        commands.entity(entity).insert(Checkpoint { id, zone, lit });
+    }
+}
+
+// 체크포인트에서 휴식 - 점화, 부활 위치 갱신, 능력치 회복, 월드 초기화
+pub fn checkpoint_interaction_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    input_mapping: Res<InputMapping>,
+    mut respawn_point: ResMut<RespawnPoint>,
+    mut lit_checkpoints: ResMut<LitCheckpoints>,
+    mut reset_events: EventWriter<WorldResetEvent>,
+    mut player_query: Query<(&Transform, &mut Health, &mut Stamina, &mut Poise, &mut StatusBuildup), With<Player>>,
+    mut checkpoint_query: Query<(Entity, &GlobalTransform, &mut Checkpoint)>,
+    pickup_query: Query<&GlobalTransform, With<ItemPickup>>,
+    chest_query: Query<(&GlobalTransform, &Chest)>,
+) {
+    if !keyboard_input.just_pressed(input_mapping.interact) {
+        return;
+    }
+
+    let Ok((player_transform, mut health, mut stamina, mut poise, mut status)) = player_query.get_single_mut() else {
+        return;
+    };
+    if health.current <= 0.0 {
+        return;
+    }
+    // 같은 키로 주운 것이 휴식 (월드 초기화) 까지 일으키지 않도록 줍기 우선
+    if is_loot_in_reach(player_transform.translation, &pickup_query, &chest_query) {
+        return;
+    }
+
+    let nearest = checkpoint_query
+        .iter_mut()
+        .map(|(entity, transform, checkpoint)| {
+            let distance = transform.translation().distance(player_transform.translation);
+            (entity, transform.translation(), checkpoint, distance)
+        })
+        .filter(|(.., distance)| *distance <= CHECKPOINT_INTERACT_RANGE)
+        .min_by(|a, b| a.3.total_cmp(&b.3));
+
+    let Some((checkpoint_entity, checkpoint_position, mut checkpoint, _)) = nearest else {
+        return;
+    };
+
+    if !checkpoint.lit {
+        checkpoint.lit = true;
+        lit_checkpoints.ids.insert(checkpoint.id.clone());
+        write_save_file(LIT_CHECKPOINTS_SAVE_PATH, "lit checkpoint", &*lit_checkpoints);
+        info!("Checkpoint lit: {} ({})", checkpoint.id, checkpoint.zone.display_name());
+    }
+
+    respawn_point.position = checkpoint_position + CHECKPOINT_RESPAWN_OFFSET;
+    respawn_point.checkpoint = Some(checkpoint_entity);
+
+    restore_player_stats(&mut health, &mut stamina, &mut poise, &mut status);
+    reset_events.send(WorldResetEvent);
+    info!("Rested at checkpoint {}", checkpoint.id);
+}
+
+// 점화 여부에 따라 체크포인트 불빛 세기 조절
+pub fn checkpoint_light_system(
+    checkpoint_query: Query<(&Checkpoint, &Children), Changed<Checkpoint>>,
+    mut light_query: Query<&mut PointLight>,
+) {
+    for (checkpoint, children) in checkpoint_query.iter() {
+        for &child in children.iter() {
+            if let Ok(mut light) = light_query.get_mut(child) {
+                light.intensity = if checkpoint.lit {
+                    CHECKPOINT_LIT_INTENSITY
+                } else {
+                    CHECKPOINT_UNLIT_INTENSITY
+                };
+            }
+        }
+    }
+}
+
+// 사망 화면에서 돌아오면 마지막으로 휴식한 체크포인트에서 부활
+pub fn respawn_player(
+    respawn_point: Res<RespawnPoint>,
+    mut reset_events: EventWriter<WorldResetEvent>,
+    mut player_query: Query<(&mut Transform, &mut Velocity, &mut Health, &mut Stamina, &mut Poise, &mut StatusBuildup, &mut LockOnState), With<Player>>,
+) {
+    for (mut transform, mut velocity, mut health, mut stamina, mut poise, mut status, mut lock_on) in player_query.iter_mut() {
+        transform.translation = respawn_point.position;
+        velocity.linvel = Vec3::ZERO;
+        restore_player_stats(&mut health, &mut stamina, &mut poise, &mut status);
+        lock_on.target = None;
+        info!("Player respawned at {:?}", respawn_point.position);
+    }
+
+    reset_events.send(WorldResetEvent);
+}
+
+// 상호작용 거리 안에서 가장 가까운 점화된 체크포인트 (빠른 이동 / 레벨업 메뉴)
+pub fn nearest_lit_checkpoint<'a>(
+    checkpoints: impl Iterator<Item = (Entity, &'a GlobalTransform, &'a Checkpoint)>,
+    position: Vec3,
+) -> Option<Entity> {
+    checkpoints
+        .filter(|(.., checkpoint)| checkpoint.lit)
+        .map(|(entity, transform, _)| (entity, transform.translation().distance(position)))
+        .filter(|(_, distance)| *distance <= CHECKPOINT_INTERACT_RANGE)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(entity, _)| entity)
+}
+
+pub fn restore_player_stats(health: &mut Health, stamina: &mut Stamina, poise: &mut Poise, status: &mut StatusBuildup) {
+    health.current = health.max;
+    stamina.current = stamina.max;
+    poise.current = poise.max;
+    poise.stagger_timer = 0.0;
+    status.buildup.clear();
+    status.active.clear();
+    status.last_attacker = None;
+}
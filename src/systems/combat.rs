@@ -3,38 +3,175 @@ use bevy::render::alpha::AlphaMode;
 use bevy_rapier3d::prelude::*;
 use crate::components::*;
 use crate::resources::*;
+use crate::systems::smithing::{wear_active_weapon, DURABILITY_LOSS_PER_HIT, DURABILITY_LOSS_PER_WALL_HIT};
+use crate::systems::world_generation::WorldZone;
+use crate::utils::math::{angle_between_vectors, facing_direction, is_within_facing_angle};
+
+// 동작이 끝나기 직전 이 시간 안에 들어온 입력은 다음 타로 예약
+const ATTACK_INPUT_BUFFER: f32 = 0.3;
+// 상태이상 축적치의 초당 감소량
+const STATUS_BUILDUP_DECAY: f32 = 5.0;
+// 벽으로 보는 면 법선의 y 성분 상한 - 이보다 크면 바닥/경사/단상 윗면으로 보고 무시
+const WALL_NORMAL_MAX_Y: f32 = 0.3;
 
 pub fn combat_system(
     time: Res<Time>,
     mouse_input: Res<ButtonInput<MouseButton>>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
     input_mapping: Res<InputMapping>,
+    moveset_db: Res<MovesetDatabase>,
     mut commands: Commands,
-    mut player_query: Query<(Entity, &Transform, &mut AttackState, &mut Stamina, &Weapon), With<Player>>,
-    mut enemy_query: Query<(Entity, &Transform, &mut Health), (With<Enemy>, Without<Player>)>,
+    mut player_query: Query<(Entity, &Transform, &mut AttackState, &mut Stamina, &mut Poise, &Weapon, &GuardState, &CriticalAttackState, &HealingFlask), With<Player>>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
 ) {
-    for (player_entity, player_transform, mut attack_state, mut stamina, weapon) in player_query.iter_mut() {
-        // 공격 쿨다운 타이머 감소
-        if attack_state.attack_timer > 0.0 {
-            attack_state.attack_timer -= time.delta_secs();
-            if attack_state.attack_timer <= 0.0 {
-                attack_state.can_attack = true;
+    let delta = time.delta_secs();
+
+    for (player_entity, player_transform, mut attack_state, mut stamina, mut poise, weapon, guard_state, critical_state, flask) in player_query.iter_mut() {
+        // 강인도가 무너지면 진행 중인 공격/차지가 끊김
+        if poise.is_staggered() {
+            if attack_state.is_attacking || attack_state.is_charging {
                 attack_state.is_attacking = false;
+                attack_state.is_charging = false;
+                attack_state.can_attack = true;
+                attack_state.attack_timer = 0.0;
+                attack_state.combo_index = 0;
+                attack_state.combo_window_timer = 0.0;
             }
+            attack_state.queued_attack = false;
+            poise.hyper_armor = false;
+            continue;
         }
 
-        // 공격 입력 처리
-        if mouse_input.just_pressed(input_mapping.attack) 
-            && attack_state.can_attack 
-            && stamina.current >= weapon.stamina_cost 
-            && !attack_state.is_attacking 
-        {
+        // 가드 브레이크 경직 / 치명타 연출 / 물약 마시는 중에는 공격 불가
+        if guard_state.is_guard_broken() || critical_state.is_active() || flask.is_drinking() {
+            attack_state.is_charging = false;
+            attack_state.queued_attack = false;
+            continue;
+        }
+
+        // 무기에 연결된 모션이 없으면 무기 수치로 단일 공격 구성
+        let fallback_moveset;
+        let moveset: &Moveset = match moveset_db.get(&weapon.moveset_id) {
+            Some(moveset) => moveset,
+            None => {
+                fallback_moveset = Moveset::single_swing(1.0, weapon.stamina_cost, weapon.attack_cooldown);
+                &fallback_moveset
+            }
+        };
+
+        let light_pressed = mouse_input.just_pressed(input_mapping.attack);
+        // Shift + 우클릭은 패링이므로 강공격에서 제외
+        let heavy_pressed = mouse_input.just_pressed(input_mapping.heavy_attack)
+            && !keyboard_input.pressed(input_mapping.block);
+
+        // 강공격 차지 - 버튼을 떼거나 풀 차지에 도달하면 발동
+        if attack_state.is_charging {
+            attack_state.charge_timer += delta;
+            let released = !mouse_input.pressed(input_mapping.heavy_attack);
+
+            if released || attack_state.charge_timer >= moveset.heavy.charge_time {
+                let heavy = &moveset.heavy;
+                let charge_ratio = (attack_state.charge_timer / heavy.charge_time).clamp(0.0, 1.0);
+                let multiplier = heavy.damage_multiplier
+                    + (heavy.charged_damage_multiplier - heavy.damage_multiplier) * charge_ratio;
+
+                attack_state.is_charging = false;
+                attack_state.is_attacking = true;
+                attack_state.can_attack = false;
+                attack_state.attack_timer = heavy.duration;
+                attack_state.current_animation = AnimationState::from_attack_key(&heavy.animation);
+                stamina.current -= heavy.stamina_cost;
+                poise.hyper_armor = heavy.hyper_armor;
+
+                spawn_attack_hitbox(
+                    &mut commands,
+                    player_entity,
+                    player_transform,
+                    weapon,
+                    weapon.damage_packet(multiplier),
+                    heavy.poise_damage,
+                    &mut meshes,
+                    &mut materials,
+                );
+                info!("Heavy attack released (charge {:.0}%)", charge_ratio * 100.0);
+            }
+            continue;
+        }
+
+        // 공격 동작 타이머 감소
+        if attack_state.is_attacking {
+            attack_state.attack_timer -= delta;
+
+            // 동작 끝부분의 입력은 선입력으로 저장
+            if light_pressed && attack_state.attack_timer <= ATTACK_INPUT_BUFFER {
+                attack_state.queued_attack = true;
+            }
+
+            if attack_state.attack_timer > 0.0 {
+                continue;
+            }
+
+            attack_state.is_attacking = false;
+            attack_state.can_attack = true;
+            poise.hyper_armor = false;
+
+            // 방금 끝난 약공격의 콤보 허용 시간 시작
+            let finished_index = (attack_state.combo_index + moveset.light_chain.len() - 1) % moveset.light_chain.len();
+            attack_state.combo_window_timer = if attack_state.combo_index == 0 {
+                0.0
+            } else {
+                moveset.light_chain[finished_index].combo_window
+            };
+        } else if attack_state.combo_window_timer > 0.0 {
+            attack_state.combo_window_timer -= delta;
+            if attack_state.combo_window_timer <= 0.0 {
+                // 콤보 시간 초과 - 처음 동작으로 복귀
+                attack_state.combo_index = 0;
+            }
+        }
+
+        if !attack_state.can_attack {
+            continue;
+        }
+
+        // 강공격 차지 시작
+        if heavy_pressed && stamina.current >= moveset.heavy.stamina_cost {
+            attack_state.is_charging = true;
+            attack_state.charge_timer = 0.0;
+            attack_state.queued_attack = false;
+            attack_state.combo_index = 0;
+            attack_state.combo_window_timer = 0.0;
+            attack_state.current_animation = AnimationState::HeavyCharge;
+            poise.hyper_armor = moveset.heavy.hyper_armor;
+            continue;
+        }
+
+        // 약공격 (콤보 연계)
+        if light_pressed || attack_state.queued_attack {
+            attack_state.queued_attack = false;
+
+            if attack_state.combo_window_timer <= 0.0 {
+                attack_state.combo_index = 0;
+            }
+
+            let swing_index = attack_state.combo_index.min(moveset.light_chain.len() - 1);
+            let swing = &moveset.light_chain[swing_index];
+
+            if stamina.current < swing.stamina_cost {
+                attack_state.combo_index = 0;
+                continue;
+            }
+
             // 공격 시작
             attack_state.is_attacking = true;
             attack_state.can_attack = false;
-            attack_state.attack_timer = weapon.attack_cooldown;
-            stamina.current -= weapon.stamina_cost;
+            attack_state.attack_timer = swing.duration;
+            attack_state.combo_window_timer = 0.0;
+            attack_state.combo_index = (swing_index + 1) % moveset.light_chain.len();
+            attack_state.current_animation = AnimationState::from_attack_key(&swing.animation);
+            stamina.current -= swing.stamina_cost;
+            poise.hyper_armor = swing.hyper_armor;
 
             // 히트박스 생성
             spawn_attack_hitbox(
@@ -42,6 +179,8 @@ pub fn combat_system(
                 player_entity,
                 player_transform,
                 weapon,
+                weapon.damage_packet(swing.damage_multiplier),
+                swing.poise_damage,
                 &mut meshes,
                 &mut materials,
             );
@@ -49,44 +188,126 @@ pub fn combat_system(
     }
 }
 
+// 지역별 적 저항 데이터(resistances.json) 로드
+pub fn load_resistance_database(mut resistance_db: ResMut<ResistanceDatabase>) {
+    match std::fs::read_to_string(RESISTANCE_DATA_PATH) {
+        Ok(json) => match serde_json::from_str::<std::collections::HashMap<WorldZone, Resistances>>(&json) {
+            Ok(zones) => {
+                info!("Loaded resistance profiles for {} zones", zones.len());
+                resistance_db.zones.extend(zones);
+            }
+            Err(e) => error!("Failed to parse resistance JSON: {}", e),
+        },
+        Err(e) => warn!("Failed to read resistance file, using default resistances: {}", e),
+    }
+}
+
+// 기획 데이터(movesets.json)로 기본 모션 덮어쓰기
+pub fn load_moveset_database(mut moveset_db: ResMut<MovesetDatabase>) {
+    match std::fs::read_to_string(MOVESET_DATA_PATH) {
+        Ok(json) => match serde_json::from_str::<std::collections::HashMap<String, Moveset>>(&json) {
+            Ok(movesets) => {
+                info!("Loaded {} movesets from {}", movesets.len(), MOVESET_DATA_PATH);
+                // 잘못된 모션은 버리고 기본 모션 사용
+                for (moveset_id, moveset) in movesets {
+                    match moveset.validate() {
+                        Ok(()) => {
+                            moveset_db.movesets.insert(moveset_id, moveset);
+                        }
+                        Err(e) => error!("Invalid moveset '{}', using default moveset: {}", moveset_id, e),
+                    }
+                }
+            }
+            Err(e) => error!("Failed to parse moveset JSON: {}", e),
+        },
+        Err(e) => warn!("Failed to read moveset file, using built-in movesets: {}", e),
+    }
+}
+
 fn spawn_attack_hitbox(
     commands: &mut Commands,
     player_entity: Entity,
     player_transform: &Transform,
     weapon: &Weapon,
+    damage: DamagePacket,
+    poise_damage: f32,
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<StandardMaterial>>,
 ) {
-    // 공격 방향 (플레이어 앞쪽)
-    let attack_position = player_transform.translation + player_transform.forward() * (weapon.attack_range / 2.0);
+    let radius = weapon.attack_range / 2.0;
+
+    // 공격 방향 (플레이어 정면 = 로컬 +Z) - 소유자 로컬 좌표로 저장해서 따라다니게 함
+    let offset = Vec3::Z * radius;
+    let attack_position = player_transform.translation + player_transform.rotation * offset;
     
     // 히트박스 엔티티 생성
     commands.spawn((
-        Mesh3d(meshes.add(Sphere::new(weapon.attack_range / 2.0).mesh().ico(2).unwrap())),
+        Mesh3d(meshes.add(Sphere::new(radius).mesh().ico(2).unwrap())),
         MeshMaterial3d(materials.add(StandardMaterial {
             base_color: Color::srgba(1.0, 0.0, 0.0, 0.3), // 반투명 빨간색
             alpha_mode: AlphaMode::Blend,
             ..default()
         })),
-        Transform::from_translation(attack_position),
+        Transform::from_translation(attack_position).with_rotation(player_transform.rotation),
         Hitbox {
-            radius: weapon.attack_range,
-            damage: weapon.damage,
+            owner: player_entity,
+            radius,
+            damage,
+            poise_damage,
             active: true,
             lifetime: 0.2,
+            offset,
+            ..default()
         },
         // 물리 센서로 설정 (충돌은 하지만 물리적 반응은 없음)
-        Collider::ball(weapon.attack_range / 2.0),
+        Collider::ball(radius),
         Sensor,
+        ActiveEvents::COLLISION_EVENTS,
     ));
 }
 
+// 활성 히트박스가 소유자(무기/캐릭터)를 따라가도록 위치 갱신
+pub fn hitbox_follow_system(
+    mut commands: Commands,
+    mut hitbox_query: Query<(Entity, &Hitbox, &mut Transform)>,
+    owner_query: Query<&Transform, Without<Hitbox>>,
+) {
+    for (hitbox_entity, hitbox, mut transform) in hitbox_query.iter_mut() {
+        let Ok(owner_transform) = owner_query.get(hitbox.owner) else {
+            // 소유자가 사라졌으면 히트박스도 제거
+            commands.entity(hitbox_entity).despawn();
+            continue;
+        };
+
+        transform.translation = owner_transform.translation + owner_transform.rotation * hitbox.offset;
+        transform.rotation = owner_transform.rotation;
+    }
+}
+
 pub fn hitbox_system(
     time: Res<Time>,
     mut commands: Commands,
+    mut collision_events: EventReader<CollisionEvent>,
+    mut damage_events: EventWriter<DamageEvent>,
+    rapier_context: ReadDefaultRapierContext,
     mut hitbox_query: Query<(Entity, &Transform, &mut Hitbox)>,
-    mut enemy_query: Query<(Entity, &Transform, &mut Health), With<Enemy>>,
+    target_query: Query<&Health, (With<Enemy>, Without<Dead>)>,
+    mut wielder_query: Query<(&Transform, &Equipment, &Weapon, &mut WeaponConditions), Without<Hitbox>>,
 ) {
+    let rapier_context = rapier_context.single();
+
+    // 센서 진입 이벤트에서 (히트박스, 대상) 쌍 수집
+    let mut contacts: Vec<(Entity, Entity)> = Vec::new();
+    for event in collision_events.read() {
+        if let CollisionEvent::Started(a, b, _) = event {
+            if hitbox_query.contains(*a) {
+                contacts.push((*a, *b));
+            } else if hitbox_query.contains(*b) {
+                contacts.push((*b, *a));
+            }
+        }
+    }
+
     for (hitbox_entity, hitbox_transform, mut hitbox) in hitbox_query.iter_mut() {
         // 히트박스 생명주기 관리
         hitbox.lifetime -= time.delta_secs();
@@ -101,36 +322,372 @@ pub fn hitbox_system(
             continue;
         }
 
-        // 적과의 충돌 검사
-        for (enemy_entity, enemy_transform, mut enemy_health) in enemy_query.iter_mut() {
-            let distance = hitbox_transform.translation.distance(enemy_transform.translation);
-            
-            if distance <= hitbox.radius {
-                // 데미지 적용
-                enemy_health.current -= hitbox.damage;
-                
-                // 히트박스 비활성화 (한 번만 적용)
-                hitbox.active = false;
-                
-                // 적이 죽었는지 확인
-                if enemy_health.current <= 0.0 {
-                    commands.entity(enemy_entity).despawn();
-                    info!("Enemy defeated!");
+        // 이벤트로 들어온 대상 + 현재 겹쳐 있는 대상 (생성 시점부터 겹친 경우 대비)
+        let mut targets: Vec<Entity> = contacts
+            .iter()
+            .filter(|(hitbox, _)| *hitbox == hitbox_entity)
+            .map(|(_, other)| *other)
+            .collect();
+        for (collider1, collider2, intersecting) in rapier_context.intersection_pairs_with(hitbox_entity) {
+            if intersecting {
+                targets.push(if collider1 == hitbox_entity { collider2 } else { collider1 });
+            }
+        }
+
+        // 휘두른 방향의 벽에 부딪히면 내구도가 크게 닳음 (벽마다 한 번, 휘두르기는 그대로 이어짐)
+        if let Ok((owner_transform, equipment, weapon, mut conditions)) = wielder_query.get_mut(hitbox.owner) {
+            let to_hitbox = (hitbox_transform.translation - owner_transform.translation) * Vec3::new(1.0, 0.0, 1.0);
+            let filter = QueryFilter::only_fixed().exclude_sensors();
+            if let Some((wall, intersection)) = rapier_context.cast_ray_and_get_normal(
+                owner_transform.translation,
+                to_hitbox.normalize_or_zero(),
+                to_hitbox.length() + hitbox.radius,
+                true,
+                filter,
+            ) {
+                // 바닥 / 경사면 / 단상 윗면처럼 위를 향한 면은 벽이 아님
+                if intersection.normal.y.abs() < WALL_NORMAL_MAX_Y && hitbox.hit_entities.insert(wall) {
+                    wear_active_weapon(equipment, weapon, &mut conditions, DURABILITY_LOSS_PER_WALL_HIT);
+                    info!("Weapon struck a wall");
                 }
-                
-                info!("Hit enemy for {} damage! Enemy health: {}", hitbox.damage, enemy_health.current);
-                break;
             }
         }
+
+        for target in targets {
+            if target == hitbox.owner || hitbox.hit_entities.contains(&target) {
+                continue;
+            }
+
+            // 이미 쓰러진 적은 무시 (다른 히트박스가 먼저 처치한 경우)
+            let Ok(enemy_health) = target_query.get(target) else {
+                continue;
+            };
+            if enemy_health.current <= 0.0 {
+                continue;
+            }
+
+            // 한 번의 공격에서 대상마다 한 번씩만 적용
+            hitbox.hit_entities.insert(target);
+
+            if let Ok((_, equipment, weapon, mut conditions)) = wielder_query.get_mut(hitbox.owner) {
+                wear_active_weapon(equipment, weapon, &mut conditions, DURABILITY_LOSS_PER_HIT);
+            }
+
+            damage_events.send(DamageEvent {
+                attacker: hitbox.owner,
+                victim: target,
+                damage: hitbox.damage.clone(),
+                poise_damage: hitbox.poise_damage,
+                hit_position: hitbox_transform.translation,
+                source: DamageSource::Melee,
+            });
+        }
     }
 }
 
+// 상태이상 축적치 감소 및 지속 효과 처리
+pub fn status_effect_system(
+    time: Res<Time>,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut status_query: Query<(Entity, &Transform, &Health, &mut StatusBuildup), Without<Dead>>,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, transform, health, mut status) in status_query.iter_mut() {
+        // 축적치는 시간이 지나면 서서히 빠짐
+        for value in status.buildup.values_mut() {
+            *value = (*value - STATUS_BUILDUP_DECAY * delta).max(0.0);
+        }
+
+        let mut tick_damage = 0.0;
+        for (status_type, remaining) in status.active.iter_mut() {
+            *remaining -= delta;
+            tick_damage += health.max * status_type.tick_damage_ratio() * delta;
+        }
+        status.active.retain(|_, remaining| *remaining > 0.0);
+
+        if tick_damage <= 0.0 || health.current <= 0.0 {
+            continue;
+        }
+
+        damage_events.send(DamageEvent {
+            attacker: entity,
+            victim: entity,
+            damage: DamagePacket::single(DamageType::Physical, tick_damage),
+            poise_damage: 0.0,
+            hit_position: transform.translation,
+            source: DamageSource::StatusEffect,
+        });
+    }
+}
+
+// 적이 생성되면 소속 지역의 저항 데이터 부여
+pub fn apply_zone_resistances(
+    mut commands: Commands,
+    resistance_db: Res<ResistanceDatabase>,
+    enemy_query: Query<(Entity, &EnemyZone), (With<Enemy>, Without<Resistances>)>,
+) {
+    for (entity, zone) in enemy_query.iter() {
+        commands
+            .entity(entity)
+            .insert((resistance_db.for_zone(zone.0), StatusBuildup::default()));
+    }
+}
+
+// 강인도 회복 및 경직 타이머 관리
+pub fn poise_system(
+    time: Res<Time>,
+    mut poise_query: Query<&mut Poise>,
+) {
+    let delta = time.delta_secs();
+
+    for mut poise in poise_query.iter_mut() {
+        if poise.stagger_timer > 0.0 {
+            poise.stagger_timer -= delta;
+        }
+
+        if poise.regen_timer > 0.0 {
+            poise.regen_timer -= delta;
+            continue;
+        }
+
+        poise.current = (poise.current + poise.regen_rate * delta).min(poise.max);
+    }
+}
+
+// 가드 브레이크 경직 시간
+pub const GUARD_BREAK_DURATION: f32 = 1.5;
+// 정면 기준 이 각도(라디안) 안의 공격만 가드 가능
+pub const GUARD_ANGLE: f32 = std::f32::consts::FRAC_PI_3;
+
+// 방어 키 입력으로 가드 상태 갱신
+pub fn guard_system(
+    time: Res<Time>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    input_mapping: Res<InputMapping>,
+    mut player_query: Query<(&mut GuardState, &Player, &AttackState, &ParryState, &Stamina, &HealingFlask), With<Player>>,
+) {
+    for (mut guard_state, player, attack_state, parry_state, stamina, flask) in player_query.iter_mut() {
+        if guard_state.guard_break_timer > 0.0 {
+            guard_state.guard_break_timer -= time.delta_secs();
+            guard_state.is_guarding = false;
+            continue;
+        }
+
+        // 구르기/공격/패링/물약 마시는 중에는 가드가 풀림
+        guard_state.is_guarding = keyboard_input.pressed(input_mapping.block)
+            && !player.is_rolling
+            && !parry_state.is_parrying()
+            && !flask.is_drinking()
+            && !attack_state.is_attacking
+            && !attack_state.is_charging
+            && stamina.current > 0.0;
+    }
+}
+
+// 패링 입력 (방어 키 + 우클릭) 및 패링 타이머 관리
+pub fn parry_system(
+    time: Res<Time>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    input_mapping: Res<InputMapping>,
+    mut player_query: Query<(&mut ParryState, &mut Stamina, &Player, &AttackState, &GuardState, &HealingFlask), With<Player>>,
+) {
+    for (mut parry_state, mut stamina, player, attack_state, guard_state, flask) in player_query.iter_mut() {
+        if parry_state.timer > 0.0 {
+            parry_state.timer -= time.delta_secs();
+            continue;
+        }
+
+        let can_parry = !player.is_rolling
+            && !attack_state.is_attacking
+            && !attack_state.is_charging
+            && !guard_state.is_guard_broken()
+            && !flask.is_drinking();
+
+        if can_parry
+            && keyboard_input.pressed(input_mapping.block)
+            && mouse_input.just_pressed(input_mapping.heavy_attack)
+            && stamina.current >= parry_state.stamina_cost
+        {
+            parry_state.timer = parry_state.duration;
+            stamina.current -= parry_state.stamina_cost;
+        }
+    }
+}
+
+// 경직된 적 앞이나 눈치채지 못한 적 뒤에서 공격하면 치명타 연출 시작
+pub fn critical_attack_system(
+    time: Res<Time>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    input_mapping: Res<InputMapping>,
+    mut player_query: Query<(Entity, &mut Transform, &mut Velocity, &mut CriticalAttackState, &AttackState, &GuardState, &Player, &Weapon, &HealingFlask), With<Player>>,
+    mut enemy_query: Query<(Entity, &Transform, &mut AIState), (With<Enemy>, Without<Player>, Without<Dead>)>,
+    mut damage_events: EventWriter<DamageEvent>,
+) {
+    let Ok((player_entity, mut player_transform, mut player_velocity, mut critical_state, attack_state, guard_state, player, weapon, flask)) = player_query.get_single_mut() else {
+        return;
+    };
+
+    // 진행 중인 연출 - 양쪽 이동을 잠그고 끝나면 데미지 적용
+    if critical_state.is_active() {
+        critical_state.timer -= time.delta_secs();
+        player_velocity.linvel.x = 0.0;
+        player_velocity.linvel.z = 0.0;
+
+        let Some(target) = critical_state.target else {
+            critical_state.timer = 0.0;
+            return;
+        };
+        let Ok((_, enemy_transform, mut ai_state)) = enemy_query.get_mut(target) else {
+            // 대상이 사라지면 연출 종료
+            critical_state.timer = 0.0;
+            critical_state.target = None;
+            return;
+        };
+
+        // 연출 동안 대상은 경직 유지
+        ai_state.current_state = AIStateType::Stunned;
+        ai_state.state_timer = ai_state.state_timer.max(0.1);
+
+        if critical_state.timer <= 0.0 {
+            damage_events.send(DamageEvent {
+                attacker: player_entity,
+                victim: target,
+                damage: weapon.damage_packet(weapon.critical_multiplier),
+                poise_damage: 0.0,
+                hit_position: enemy_transform.translation + Vec3::Y,
+                source: DamageSource::Critical,
+            });
+            info!("{:?} landed on {:?}", critical_state.kind, target);
+
+            // 살아남으면 바로 플레이어를 추적 (쓰러지면 사망 처리에서 정리)
+            ai_state.current_state = AIStateType::Chase;
+            ai_state.state_timer = 0.0;
+            ai_state.last_known_player_position = player_transform.translation;
+            critical_state.target = None;
+        }
+        return;
+    }
+
+    if !mouse_input.just_pressed(input_mapping.attack)
+        || attack_state.is_attacking
+        || attack_state.is_charging
+        || guard_state.is_guard_broken()
+        || player.is_rolling
+        || flask.is_drinking()
+    {
+        return;
+    }
+
+    // 가장 가까운 치명타 대상 찾기
+    let mut best: Option<(Entity, CriticalKind, f32, Transform)> = None;
+    for (enemy_entity, enemy_transform, ai_state) in enemy_query.iter() {
+        let distance = player_transform.translation.distance(enemy_transform.translation);
+        if !is_within_facing_angle(&player_transform, enemy_transform.translation, critical_state.facing_angle) {
+            continue;
+        }
+
+        // 적 정면의 반대쪽(등 뒤)에 플레이어가 있는지
+        let from_player = enemy_transform.translation - player_transform.translation;
+        let is_behind = angle_between_vectors(
+            facing_direction(enemy_transform),
+            Vec3::new(from_player.x, 0.0, from_player.z),
+        ) <= critical_state.backstab_angle;
+
+        let kind = match ai_state.current_state {
+            AIStateType::Stunned if distance <= critical_state.riposte_distance => CriticalKind::Riposte,
+            AIStateType::Idle | AIStateType::Patrol
+                if distance <= critical_state.backstab_distance && is_behind => CriticalKind::Backstab,
+            _ => continue,
+        };
+
+        if best.as_ref().map_or(true, |(_, _, best_distance, _)| distance < *best_distance) {
+            best = Some((enemy_entity, kind, distance, *enemy_transform));
+        }
+    }
+
+    let Some((target, kind, _, enemy_transform)) = best else {
+        return;
+    };
+
+    // 연출 위치로 정렬 - 앞잡기는 적 정면, 뒤잡기는 적 등 뒤
+    let enemy_facing = facing_direction(&enemy_transform);
+    let side = if kind == CriticalKind::Riposte { 1.0 } else { -1.0 };
+    let snap_position = enemy_transform.translation + enemy_facing * side * 1.2;
+    player_transform.translation.x = snap_position.x;
+    player_transform.translation.z = snap_position.z;
+    let to_enemy = Vec3::new(
+        enemy_transform.translation.x - snap_position.x,
+        0.0,
+        enemy_transform.translation.z - snap_position.z,
+    );
+    if to_enemy.length_squared() > f32::EPSILON {
+        player_transform.rotation = Quat::from_rotation_arc(Vec3::Z, to_enemy.normalize());
+    }
+
+    critical_state.timer = critical_state.duration;
+    critical_state.target = Some(target);
+    critical_state.kind = kind;
+    player_velocity.linvel = Vec3::ZERO;
+
+    if let Ok((_, _, mut ai_state)) = enemy_query.get_mut(target) {
+        ai_state.current_state = AIStateType::Stunned;
+        ai_state.state_timer = critical_state.duration;
+    }
+    info!("{:?} started on enemy {:?}", kind, target);
+}
+
+// 무기 전환 - 다른 손 칸에 든 무기로 바꿔 듦 (바뀐 장비는 apply_equipment 가 반영)
 pub fn weapon_system(
-    mut player_query: Query<(&mut AttackState, &mut Weapon), With<Player>>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    input_mapping: Res<InputMapping>,
+    mut player_query: Query<(
+        &mut Equipment,
+        &Player,
+        &Health,
+        &Poise,
+        &AttackState,
+        &GuardState,
+        &ParryState,
+        &CriticalAttackState,
+        &HealingFlask,
+    ), With<Player>>,
 ) {
-    // 무기 관련 로직 (내구도, 업그레이드 등)
-    for (mut attack_state, mut weapon) in player_query.iter_mut() {
-        // 현재는 기본 무기만 사용
-        // 나중에 무기 교체, 업그레이드 등 구현 예정
+    let switch_right = keyboard_input.just_pressed(input_mapping.switch_right_hand);
+    let switch_left = keyboard_input.just_pressed(input_mapping.switch_left_hand);
+    if !switch_right && !switch_left {
+        return;
+    }
+
+    let Ok((mut equipment, player, health, poise, attack_state, guard_state, parry_state, critical_state, flask)) = player_query.get_single_mut() else {
+        return;
+    };
+
+    let can_switch = health.current > 0.0
+        && !player.is_rolling
+        && !attack_state.is_attacking
+        && !attack_state.is_charging
+        && !guard_state.is_guard_broken()
+        && !parry_state.is_parrying()
+        && !critical_state.is_active()
+        && !poise.is_staggered()
+        && !flask.is_drinking();
+    if !can_switch {
+        return;
+    }
+
+    // 빈 칸으로는 바꾸지 않음
+    if switch_right {
+        let next = (equipment.active_right + 1) % equipment.right_hand.len();
+        if equipment.right_hand[next].is_some() {
+            equipment.active_right = next;
+        }
     }
-}
\ No newline at end of file
+    if switch_left {
+        let next = (equipment.active_left + 1) % equipment.left_hand.len();
+        if equipment.left_hand[next].is_some() {
+            equipment.active_left = next;
+        }
+    }
+}
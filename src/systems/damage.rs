@@ -0,0 +1,270 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use crate::components::*;
+use crate::resources::ItemDatabase;
+use crate::states::GameState;
+use crate::systems::combat::{GUARD_ANGLE, GUARD_BREAK_DURATION};
+use crate::systems::enemy_ai::AttackEffect;
+use crate::systems::smithing::{wear_guarding_item, DURABILITY_LOSS_PER_BLOCK};
+use crate::utils::math::is_within_facing_angle;
+
+// 쓰러진 적이 사라지기까지의 시간
+const CORPSE_DESPAWN_DELAY: f32 = 3.0;
+
+// 속성 저항과 상태이상을 반영한 최종 데미지 계산
+pub fn resolve_typed_damage(
+    packet: &DamagePacket,
+    max_health: f32,
+    resistances: Option<&Resistances>,
+    status: Option<&mut StatusBuildup>,
+    buildup_scale: f32,
+) -> f32 {
+    let default_resistances = Resistances::default();
+    let resistances = resistances.unwrap_or(&default_resistances);
+    let mut damage = resistances.mitigate(packet);
+
+    if let Some(status) = status {
+        damage *= status.damage_taken_multiplier();
+
+        // 임계치를 넘은 상태이상은 즉시 피해 추가
+        for status_type in status.accumulate(packet, resistances, buildup_scale) {
+            damage += max_health * status_type.burst_damage_ratio();
+            info!("{:?} triggered!", status_type);
+        }
+    }
+
+    damage
+}
+
+// 모든 피해는 여기서 처리 - 무적/패링/가드/저항/강인도 순으로 판정
+pub fn damage_resolution_system(
+    mut commands: Commands,
+    mut damage_events: EventReader<DamageEvent>,
+    mut death_events: EventWriter<DeathEvent>,
+    mut victim_query: Query<(&Transform, &mut Health, Option<&mut Poise>, Option<&Resistances>, Option<&mut StatusBuildup>, Option<&mut AIState>, Option<&mut HealingFlask>), Without<Dead>>,
+    mut defender_query: Query<(&Player, &mut Stamina, &mut GuardState, &ParryState, &Weapon, &Equipment, &mut WeaponConditions)>,
+    enemy_query: Query<&Enemy>,
+    item_db: Res<ItemDatabase>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for event in damage_events.read() {
+        let attacker_position = victim_query
+            .get(event.attacker)
+            .map(|(transform, ..)| transform.translation)
+            .unwrap_or(event.hit_position);
+
+        let Ok((victim_transform, mut health, poise, resistances, mut status, ai_state, flask)) = victim_query.get_mut(event.victim) else {
+            continue;
+        };
+
+        // 이미 쓰러진 대상은 무시 (같은 프레임에 여러 번 맞은 경우)
+        if health.current <= 0.0 {
+            continue;
+        }
+
+        // 상태이상 지속 피해는 판정 없이 그대로 적용
+        if event.source == DamageSource::StatusEffect {
+            health.current -= event.damage.total();
+            if health.current <= 0.0 {
+                death_events.send(DeathEvent {
+                    entity: event.victim,
+                    killer: status.as_ref().and_then(|status| status.last_attacker),
+                    position: victim_transform.translation,
+                });
+            }
+            continue;
+        }
+
+        let mut blocked = false;
+        let mut damage = 0.0;
+        let mut parried = false;
+
+        if let Ok((player, mut stamina, mut guard_state, parry_state, weapon, equipment, mut conditions)) = defender_query.get_mut(event.victim) {
+            let from_front = is_within_facing_angle(victim_transform, attacker_position, GUARD_ANGLE);
+            let is_melee = event.source == DamageSource::Melee;
+            let is_ranged = event.source == DamageSource::Projectile;
+            let is_area = event.source == DamageSource::Area;
+
+            // 구르기 무적 구간에는 피해 없음
+            if (is_melee || is_ranged || is_area) && player.is_invulnerable() {
+                info!("Player dodged attack from {:?}!", event.attacker);
+                continue;
+            }
+
+            // 패링 유효 구간에 정면에서 들어온 공격은 무효화 - 상태이상 축적도 없음
+            if is_melee && parry_state.is_window_active() && from_front {
+                parried = true;
+            } else {
+                // 정면에서 들어온 공격은 가드 - 상태이상 축적도 흡수율만큼 줄어듦
+                let guarded = (is_melee || is_ranged) && guard_state.is_guarding && from_front;
+                let buildup_scale = if guarded { 1.0 - weapon.guard_absorption } else { 1.0 };
+                damage = resolve_typed_damage(&event.damage, health.max, resistances, status.as_deref_mut(), buildup_scale);
+
+                // 가드 - 흡수한 피해만큼 스테미나 소모
+                if guarded {
+                    blocked = true;
+                    let absorbed = damage * weapon.guard_absorption;
+                    let stamina_drain = damage * (1.0 - weapon.guard_stability);
+                    damage -= absorbed;
+                    stamina.current -= stamina_drain;
+                    wear_guarding_item(&item_db, equipment, weapon, &mut conditions, DURABILITY_LOSS_PER_BLOCK);
+
+                    if stamina.current <= 0.0 {
+                        // 가드 브레이크 - 스테미나 고갈로 경직
+                        stamina.current = 0.0;
+                        guard_state.is_guarding = false;
+                        guard_state.guard_break_timer = GUARD_BREAK_DURATION;
+                        info!("Guard broken!");
+                    } else {
+                        info!("Blocked attack! Absorbed {} damage, stamina: {}", absorbed, stamina.current);
+                    }
+                }
+            }
+        } else {
+            damage = resolve_typed_damage(&event.damage, health.max, resistances, status.as_deref_mut(), 1.0);
+        }
+
+        // 상태이상을 쌓은 쪽 기록 (패링으로 막은 공격 제외)
+        if !parried && !event.damage.status_buildup.is_empty() {
+            if let Some(status) = status.as_deref_mut() {
+                status.last_attacker = Some(event.attacker);
+            }
+        }
+
+        if parried {
+            let stun_duration = defender_query
+                .get(event.victim)
+                .map(|(_, _, _, parry_state, ..)| parry_state.stun_duration)
+                .unwrap_or(0.0);
+
+            // 공격자를 경직
+            if let Ok((_, _, _, _, _, Some(mut attacker_ai), _)) = victim_query.get_mut(event.attacker) {
+                attacker_ai.current_state = AIStateType::Stunned;
+                attacker_ai.state_timer = stun_duration;
+                if let Ok(enemy) = enemy_query.get(event.attacker) {
+                    attacker_ai.attack_timer = enemy.attack_cooldown;
+                }
+            }
+            info!("Parried {:?}! Stunned for {}s", event.attacker, stun_duration);
+
+            // 패링 이펙트 (노란 섬광)
+            commands.spawn((
+                Mesh3d(meshes.add(Sphere::new(0.4).mesh().ico(2).unwrap())),
+                MeshMaterial3d(materials.add(StandardMaterial {
+                    base_color: Color::srgb(1.0, 0.9, 0.3),
+                    emissive: LinearRgba::new(1.0, 0.9, 0.3, 1.0),
+                    ..default()
+                })),
+                Transform::from_translation(event.hit_position),
+                AttackEffect { lifetime: 0.2 },
+            ));
+            continue;
+        }
+
+        health.current -= damage;
+        info!("{:?} takes {} damage from {:?}! Health: {}", event.victim, damage, event.attacker, health.current);
+
+        // 맞으면 물약 마시기가 끊김 (가드로 막은 경우 제외)
+        if let Some(mut flask) = flask {
+            if flask.is_drinking() && !blocked {
+                flask.interrupt();
+                info!("Flask interrupted by hit");
+            }
+        }
+
+        // 피격 이펙트 (빨간 구체)
+        commands.spawn((
+            Mesh3d(meshes.add(Sphere::new(0.5).mesh().ico(2).unwrap())),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: Color::srgb(1.0, 0.2, 0.2),
+                ..default()
+            })),
+            Transform::from_translation(event.hit_position),
+            AttackEffect { lifetime: 0.3 },
+        ));
+
+        if health.current <= 0.0 {
+            death_events.send(DeathEvent {
+                entity: event.victim,
+                killer: Some(event.attacker),
+                position: victim_transform.translation,
+            });
+            continue;
+        }
+
+        // 가드하지 못한 공격은 강인도를 깎고, 무너지면 하던 행동을 끊음 (패링 경직은 유지)
+        if blocked {
+            continue;
+        }
+        if let Some(mut poise) = poise {
+            if poise.apply_damage(event.poise_damage) {
+                info!("{:?} staggered!", event.victim);
+                if let Some(mut ai_state) = ai_state {
+                    if !matches!(ai_state.current_state, AIStateType::Stunned) {
+                        ai_state.current_state = AIStateType::Staggered;
+                        ai_state.state_timer = poise.stagger_duration;
+                    }
+                }
+            }
+        }
+    }
+}
+
+// 사망 처리 - 적은 쓰러지는 연출 후 제거, 플레이어는 사망 화면으로
+pub fn death_system(
+    mut commands: Commands,
+    mut death_events: EventReader<DeathEvent>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut enemy_query: Query<&mut Velocity, (With<Enemy>, Without<Dead>)>,
+    player_query: Query<(), With<Player>>,
+) {
+    for event in death_events.read() {
+        if player_query.contains(event.entity) {
+            info!("Player died at {:?}", event.position);
+            next_state.set(GameState::Death);
+            continue;
+        }
+
+        let Ok(mut velocity) = enemy_query.get_mut(event.entity) else {
+            if let Some(killer) = event.killer {
+                info!("{:?} was killed by {:?}", event.entity, killer);
+            }
+            continue;
+        };
+
+        velocity.linvel = Vec3::ZERO;
+        commands
+            .entity(event.entity)
+            .insert((Dead { despawn_timer: CORPSE_DESPAWN_DELAY }, RigidBody::Fixed))
+            .remove::<Collider>();
+        info!("Enemy {:?} defeated at {:?}", event.entity, event.position);
+    }
+}
+
+// 쓰러진 적이 옆으로 넘어지며 가라앉은 뒤 제거
+pub fn corpse_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut corpse_query: Query<(Entity, &mut Transform, &mut Dead)>,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, mut transform, mut dead) in corpse_query.iter_mut() {
+        dead.despawn_timer -= delta;
+
+        if dead.despawn_timer <= 0.0 {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        // 처음 0.5초 동안 90도 넘어지고, 마지막 1초 동안 가라앉음
+        if dead.despawn_timer > CORPSE_DESPAWN_DELAY - 0.5 {
+            transform.rotate_local_x(-std::f32::consts::PI * delta);
+        }
+        if dead.despawn_timer < 1.0 {
+            transform.translation.y -= delta;
+        }
+    }
+}
+
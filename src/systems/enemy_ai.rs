@@ -1,10 +1,22 @@
 use bevy::prelude::*;
 use bevy_rapier3d::prelude::*;
 use crate::components::*;
+use crate::systems::projectile::{spawn_projectile, GRAVITY};
+use crate::utils::math::{ballistic_lead_velocity, facing_direction};
+
+// 궁수 - 사거리에 들어온 뒤 첫 발까지 활을 당기는 시간, 사격 중 이동 속도 배율
+const ARCHER_DRAW_TIME: f32 = 0.6;
+const ARCHER_STRAFE_SPEED_SCALE: f32 = 0.4;
+const ARROW_GRAVITY_SCALE: f32 = 0.5;
+// 순찰 - 웨이포인트에 이만큼 (수평 거리) 가까워지면 도착으로 처리
+const PATROL_ARRIVE_DISTANCE: f32 = 0.5;
+// 추적 중 플레이어가 이 시간 이상 보이지 않으면 마지막으로 본 위치를 탐색
+const LOSE_SIGHT_TIME: f32 = 2.0;
 
 pub fn enemy_ai_system(
     time: Res<Time>,
-    mut enemy_query: Query<(Entity, &Transform, &mut AIState, &Enemy), (With<Enemy>, Without<Player>)>,
+    // 보스는 boss_ai_system 에서 처리
+    mut enemy_query: Query<(Entity, &mut Transform, &mut AIState, &Enemy, Option<&mut Poise>, Option<&mut PatrolPath>, &mut Perception), (With<Enemy>, Without<Player>, Without<Dead>, Without<BossState>)>,
     player_query: Query<&Transform, With<Player>>,
     mut velocity_query: Query<&mut Velocity>,
 ) {
@@ -12,11 +24,17 @@ pub fn enemy_ai_system(
         return;
     };
 
-    for (enemy_entity, enemy_transform, mut ai_state, enemy) in enemy_query.iter_mut() {
+    for (enemy_entity, mut enemy_transform, mut ai_state, enemy, poise, mut patrol, mut perception) in enemy_query.iter_mut() {
         // 상태 타이머 감소
         ai_state.state_timer -= time.delta_secs();
         ai_state.attack_timer -= time.delta_secs();
 
+        // 슈퍼아머 공격을 가진 적은 공격 상태 동안 경직되지 않음
+        if let Some(mut poise) = poise {
+            poise.hyper_armor = enemy.attack_hyper_armor
+                && matches!(ai_state.current_state, AIStateType::Attack);
+        }
+
         // 플레이어와의 거리 계산
         let distance_to_player = enemy_transform.translation.distance(player_transform.translation);
         let direction_to_player = (player_transform.translation - enemy_transform.translation).normalize();
@@ -24,17 +42,50 @@ pub fn enemy_ai_system(
         // AI 상태 머신
         match ai_state.current_state {
             AIStateType::Idle => {
-                // 플레이어가 감지 범위에 들어오면 추적 시작
-                if distance_to_player <= enemy.detection_range {
+                // 경계도가 다 차서 플레이어를 발견하면 추적 시작
+                if perception.is_alerted() {
                     ai_state.current_state = AIStateType::Chase;
                     ai_state.target = Some(enemy_entity);
                     ai_state.last_known_player_position = player_transform.translation;
                 }
                 
                 // Idle 상태에서는 가만히 있거나 순찰
-                if ai_state.state_timer <= 0.0 {
+                else if ai_state.state_timer <= 0.0 {
                     ai_state.current_state = AIStateType::Patrol;
-                    ai_state.state_timer = 3.0; // 3초간 순찰
+                    // 순찰 경로가 있으면 바로 출발 (타이머는 웨이포인트 대기에 사용)
+                    ai_state.state_timer = if patrol.is_some() { 0.0 } else { 3.0 }; // 3초간 순찰
+                }
+            }
+
+            AIStateType::Patrol if patrol.as_ref().is_some_and(|path| !path.points.is_empty()) => {
+                let Some(path) = patrol.as_deref_mut() else {
+                    continue;
+                };
+                let target = path.current_point().unwrap_or(enemy_transform.translation);
+                let offset = Vec3::new(target.x - enemy_transform.translation.x, 0.0, target.z - enemy_transform.translation.z);
+
+                // 웨이포인트에 도착하면 wait_time 동안 멈춰 서 있다가 다음 지점으로
+                let mut move_direction = Vec3::ZERO;
+                if ai_state.state_timer <= 0.0 {
+                    if offset.length() <= PATROL_ARRIVE_DISTANCE {
+                        path.advance();
+                        ai_state.state_timer = path.wait_time;
+                    } else {
+                        move_direction = offset.normalize();
+                        face_towards(&mut enemy_transform, move_direction, 5.0 * time.delta_secs());
+                    }
+                }
+
+                if let Ok(mut velocity) = velocity_query.get_mut(enemy_entity) {
+                    velocity.linvel.x = move_direction.x * path.patrol_speed;
+                    velocity.linvel.z = move_direction.z * path.patrol_speed;
+                }
+
+                // 플레이어 발견
+                if perception.is_alerted() {
+                    ai_state.current_state = AIStateType::Chase;
+                    ai_state.target = Some(enemy_entity);
+                    ai_state.last_known_player_position = player_transform.translation;
                 }
             }
 
@@ -45,17 +96,75 @@ pub fn enemy_ai_system(
                     ai_state.state_timer = 2.0; // 2초간 정지
                 }
 
-                // 플레이어 감지
-                if distance_to_player <= enemy.detection_range {
+                // 플레이어 발견
+                if perception.is_alerted() {
                     ai_state.current_state = AIStateType::Chase;
                     ai_state.target = Some(enemy_entity);
                     ai_state.last_known_player_position = player_transform.translation;
                 }
             }
 
+            AIStateType::Chase if enemy.is_ranged() => {
+                if perception.sees_player {
+                    ai_state.last_known_player_position = player_transform.translation;
+                }
+
+                let flat_direction = Vec3::new(direction_to_player.x, 0.0, direction_to_player.z).normalize_or_zero();
+                let mut move_direction = Vec3::ZERO;
+                if distance_to_player > enemy.detection_range * 2.0 || perception.time_since_seen > LOSE_SIGHT_TIME {
+                    ai_state.current_state = AIStateType::SearchLastKnown;
+                    ai_state.state_timer = 5.0;
+                }
+                // 너무 가까우면 플레이어를 바라본 채 뒷걸음질
+                else if distance_to_player < enemy.retreat_range {
+                    move_direction = -flat_direction;
+                }
+                // 사거리 안이면 활을 당기기 시작
+                else if distance_to_player <= enemy.attack_range {
+                    ai_state.current_state = AIStateType::Attack;
+                    ai_state.attack_timer = ai_state.attack_timer.max(ARCHER_DRAW_TIME);
+                }
+                else {
+                    move_direction = flat_direction;
+                }
+
+                face_towards(&mut enemy_transform, direction_to_player, 8.0 * time.delta_secs());
+                if let Ok(mut velocity) = velocity_query.get_mut(enemy_entity) {
+                    velocity.linvel.x = move_direction.x * enemy.move_speed;
+                    velocity.linvel.z = move_direction.z * enemy.move_speed;
+                }
+            }
+
+            AIStateType::Attack if enemy.is_ranged() => {
+                // 실제 발사는 enemy_attack_system 에서 처리 - 여기서는 다음 사격까지 대기
+                if ai_state.attack_timer <= 0.0 {
+                    ai_state.attack_timer = enemy.attack_cooldown;
+                }
+
+                // 너무 가까워지거나 사거리를 벗어나면 다시 거리 조절
+                if distance_to_player < enemy.retreat_range || distance_to_player > enemy.attack_range * 1.2 {
+                    ai_state.current_state = AIStateType::Chase;
+                }
+
+                // 사격하면서 선호 거리 쪽으로 천천히 이동
+                let flat_direction = Vec3::new(direction_to_player.x, 0.0, direction_to_player.z).normalize_or_zero();
+                let drift = if distance_to_player < enemy.preferred_range - 1.0 {
+                    -flat_direction
+                } else if distance_to_player > enemy.preferred_range + 1.0 {
+                    flat_direction
+                } else {
+                    Vec3::ZERO
+                };
+                face_towards(&mut enemy_transform, direction_to_player, 8.0 * time.delta_secs());
+                if let Ok(mut velocity) = velocity_query.get_mut(enemy_entity) {
+                    velocity.linvel.x = drift.x * enemy.move_speed * ARCHER_STRAFE_SPEED_SCALE;
+                    velocity.linvel.z = drift.z * enemy.move_speed * ARCHER_STRAFE_SPEED_SCALE;
+                }
+            }
+
             AIStateType::Chase => {
                 // 플레이어 위치 업데이트
-                if distance_to_player <= enemy.detection_range * 1.5 {
+                if perception.sees_player {
                     ai_state.last_known_player_position = player_transform.translation;
                 }
 
@@ -64,13 +173,14 @@ pub fn enemy_ai_system(
                     ai_state.current_state = AIStateType::Attack;
                     ai_state.attack_timer = enemy.attack_cooldown;
                 } 
-                // 너무 멀어지면 마지막 알려진 위치로 이동
-                else if distance_to_player > enemy.detection_range * 2.0 {
+                // 너무 멀어지거나 시야에서 놓치면 마지막 알려진 위치로 이동
+                else if distance_to_player > enemy.detection_range * 2.0 || perception.time_since_seen > LOSE_SIGHT_TIME {
                     ai_state.current_state = AIStateType::SearchLastKnown;
                     ai_state.state_timer = 5.0; // 5초간 탐색
                 }
                 // 플레이어를 향해 이동
                 else {
+                    face_towards(&mut enemy_transform, direction_to_player, 8.0 * time.delta_secs());
                     if let Ok(mut velocity) = velocity_query.get_mut(enemy_entity) {
                         velocity.linvel = direction_to_player * enemy.move_speed;
                         velocity.linvel.y = velocity.linvel.y; // Y축 속도 유지
@@ -94,7 +204,8 @@ pub fn enemy_ai_system(
                     ai_state.current_state = AIStateType::Chase;
                 }
 
-                // 공격 중에는 움직이지 않음
+                // 공격 중에는 움직이지 않고 플레이어를 바라봄
+                face_towards(&mut enemy_transform, direction_to_player, 8.0 * time.delta_secs());
                 if let Ok(mut velocity) = velocity_query.get_mut(enemy_entity) {
                     velocity.linvel.x = 0.0;
                     velocity.linvel.z = 0.0;
@@ -108,6 +219,11 @@ pub fn enemy_ai_system(
                 if distance_to_last_known < 1.0 || ai_state.state_timer <= 0.0 {
                     ai_state.current_state = AIStateType::Idle;
                     ai_state.state_timer = 2.0;
+                    perception.stand_down();
+                    // 순찰 경로로 돌아갈 때는 가장 가까운 웨이포인트부터
+                    if let Some(path) = patrol.as_deref_mut() {
+                        path.rejoin_nearest(enemy_transform.translation);
+                    }
                     
                     if let Ok(mut velocity) = velocity_query.get_mut(enemy_entity) {
                         velocity.linvel.x = 0.0;
@@ -116,6 +232,7 @@ pub fn enemy_ai_system(
                 } else {
                     // 마지막 알려진 위치로 이동
                     let direction_to_last_known = (ai_state.last_known_player_position - enemy_transform.translation).normalize();
+                    face_towards(&mut enemy_transform, direction_to_last_known, 5.0 * time.delta_secs());
                     if let Ok(mut velocity) = velocity_query.get_mut(enemy_entity) {
                         velocity.linvel = direction_to_last_known * enemy.move_speed * 0.5; // 천천히 이동
                         velocity.linvel.y = velocity.linvel.y; // Y축 속도 유지
@@ -123,7 +240,7 @@ pub fn enemy_ai_system(
                 }
 
                 // 플레이어를 다시 발견하면 추적
-                if distance_to_player <= enemy.detection_range {
+                if perception.is_alerted() && perception.sees_player {
                     ai_state.current_state = AIStateType::Chase;
                     ai_state.last_known_player_position = player_transform.translation;
                 }
@@ -141,46 +258,90 @@ pub fn enemy_ai_system(
                     ai_state.state_timer = 1.0;
                 }
             }
+
+            AIStateType::Staggered => {
+                // 강인도 경직 - 잠시 멈췄다가 바로 추적 재개
+                if let Ok(mut velocity) = velocity_query.get_mut(enemy_entity) {
+                    velocity.linvel.x = 0.0;
+                    velocity.linvel.z = 0.0;
+                }
+
+                if ai_state.state_timer <= 0.0 {
+                    ai_state.current_state = AIStateType::Chase;
+                    ai_state.attack_timer = enemy.attack_cooldown;
+                    ai_state.last_known_player_position = player_transform.translation;
+                }
+            }
         }
     }
 }
 
+// 적이 진행 방향(로컬 +Z)을 바라보도록 회전 - 뒤잡기 판정에 사용
+fn face_towards(transform: &mut Transform, direction: Vec3, t: f32) {
+    let flat = Vec3::new(direction.x, 0.0, direction.z);
+    if flat.length_squared() < f32::EPSILON {
+        return;
+    }
+    let target_rotation = Quat::from_rotation_arc(Vec3::Z, flat.normalize());
+    transform.rotation = transform.rotation.slerp(target_rotation, t.min(1.0));
+}
+
 pub fn enemy_attack_system(
     mut commands: Commands,
-    mut enemy_query: Query<(Entity, &Transform, &mut AIState, &Enemy), With<Enemy>>,
-    mut player_query: Query<(Entity, &Transform, &mut Health), With<Player>>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    // 보스 공격은 boss_ai_system 에서 처리
+    enemy_query: Query<(Entity, &Transform, &AIState, &Enemy), (With<Enemy>, Without<Dead>, Without<BossState>)>,
+    player_query: Query<(Entity, &Transform, &Velocity), With<Player>>,
+    mut damage_events: EventWriter<DamageEvent>,
 ) {
-    let Ok((player_entity, player_transform, mut player_health)) = player_query.get_single_mut() else {
+    let Ok((player_entity, player_transform, player_velocity)) = player_query.get_single() else {
         return;
     };
 
-    for (enemy_entity, enemy_transform, mut ai_state, enemy) in enemy_query.iter_mut() {
+    for (enemy_entity, enemy_transform, ai_state, enemy) in enemy_query.iter() {
         if matches!(ai_state.current_state, AIStateType::Attack) && ai_state.attack_timer <= 0.0 {
             let distance_to_player = enemy_transform.translation.distance(player_transform.translation);
-            
-            if distance_to_player <= enemy.attack_range {
-                // 플레이어에게 데미지
-                player_health.current -= enemy.attack_damage;
-                info!("Player takes {} damage! Health: {}", enemy.attack_damage, player_health.current);
-
-                // 공격 이펙트 생성 (간단한 빨간 구체)
-                commands.spawn((
-                    Mesh3d(meshes.add(Sphere::new(0.5).mesh().ico(2).unwrap())),
-                    MeshMaterial3d(materials.add(StandardMaterial {
-                        base_color: Color::srgb(1.0, 0.2, 0.2),
-                        ..default()
-                    })),
-                    Transform::from_translation(player_transform.translation + Vec3::Y * 1.0),
-                    AttackEffect { lifetime: 0.3 },
-                ));
 
-                // 플레이어가 죽었는지 확인
-                if player_health.current <= 0.0 {
-                    info!("Player died!");
-                    // 사망 처리는 별도 시스템에서
+            if enemy.is_ranged() {
+                if distance_to_player > enemy.attack_range * 1.2 {
+                    continue;
                 }
+                // 플레이어가 움직이는 방향을 예측해서 화살 발사 (점프/낙하 속도는 무시)
+                let origin = enemy_transform.translation + Vec3::Y * 0.8 + facing_direction(enemy_transform) * 0.6;
+                let target = player_transform.translation + Vec3::Y * 0.5;
+                let target_velocity = player_velocity.linvel * Vec3::new(1.0, 0.0, 1.0);
+                let velocity = ballistic_lead_velocity(
+                    origin,
+                    target,
+                    target_velocity,
+                    enemy.projectile_speed,
+                    GRAVITY * ARROW_GRAVITY_SCALE,
+                );
+                spawn_projectile(
+                    &mut commands,
+                    &mut meshes,
+                    &mut materials,
+                    Projectile {
+                        owner: enemy_entity,
+                        damage: enemy.attack_packet(),
+                        poise_damage: enemy.poise_damage,
+                        gravity_scale: ARROW_GRAVITY_SCALE,
+                        ..default()
+                    },
+                    origin,
+                    velocity,
+                );
+            } else if distance_to_player <= enemy.attack_range {
+                // 무적/패링/가드 판정과 피해 적용은 damage_resolution_system 에서 처리
+                damage_events.send(DamageEvent {
+                    attacker: enemy_entity,
+                    victim: player_entity,
+                    damage: enemy.attack_packet(),
+                    poise_damage: enemy.poise_damage,
+                    hit_position: player_transform.translation + Vec3::Y * 1.0,
+                    source: DamageSource::Melee,
+                });
             }
         }
     }
@@ -0,0 +1,110 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use crate::components::*;
+
+// 스폰 정의가 생기면 처음 한 번 적 생성
+pub fn spawn_initial_enemies(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    definition_query: Query<(Entity, &EnemySpawnDefinition), Added<EnemySpawnDefinition>>,
+) {
+    for (definition_entity, definition) in definition_query.iter() {
+        spawn_enemy(&mut commands, &mut meshes, &mut materials, definition_entity, definition);
+    }
+}
+
+// 월드 초기화 - 보스가 아닌 적은 살아 있든 죽었든 정의대로 다시 생성
+pub fn reset_enemies_system(
+    mut commands: Commands,
+    mut reset_events: EventReader<WorldResetEvent>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    enemy_query: Query<(Entity, &SpawnedFrom)>,
+    definition_query: Query<(Entity, &EnemySpawnDefinition)>,
+) {
+    if reset_events.read().count() == 0 {
+        return;
+    }
+
+    for (enemy_entity, spawned_from) in enemy_query.iter() {
+        let is_boss = definition_query
+            .get(spawned_from.0)
+            .is_ok_and(|(_, definition)| definition.is_boss());
+        if !is_boss {
+            commands.entity(enemy_entity).despawn_recursive();
+        }
+    }
+
+    let mut respawned = 0;
+    for (definition_entity, definition) in definition_query.iter() {
+        if definition.is_boss() {
+            continue;
+        }
+        spawn_enemy(&mut commands, &mut meshes, &mut materials, definition_entity, definition);
+        respawned += 1;
+    }
+    info!("World reset: respawned {} enemies", respawned);
+}
+
+pub fn spawn_enemy(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    definition_entity: Entity,
+    definition: &EnemySpawnDefinition,
+) -> Entity {
+    // 보스는 더 크게
+    let (mesh, collider) = match definition.enemy.ai_type {
+        EnemyType::Boss => (Capsule3d::new(1.2, 2.4).mesh(), Collider::capsule_y(1.2, 1.2)),
+        _ => (Capsule3d::default().mesh(), Collider::capsule_y(1.0, 0.5)),
+    };
+    let enemy_entity = commands.spawn((
+        Mesh3d(meshes.add(mesh)),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: match definition.enemy.ai_type {
+                EnemyType::Archer => Color::srgb(0.6, 0.5, 0.25),
+                EnemyType::Boss => Color::srgb(0.5, 0.2, 0.6),
+                _ => Color::srgb(0.3, 0.8, 0.3),
+            },
+            ..default()
+        })),
+        Transform::from_translation(definition.position).with_rotation(definition.rotation),
+        definition.enemy.clone(),
+        Health { current: definition.max_health, max: definition.max_health },
+        Poise::with_max(definition.max_poise),
+        EnemyZone(definition.zone),
+        AIState::default(),
+        RigidBody::Dynamic,
+        collider,
+        LockedAxes::ROTATION_LOCKED,
+        Velocity::default(),
+        SpawnedFrom(definition_entity),
+    )).id();
+
+    if let Some(patrol) = &definition.patrol {
+        commands.entity(enemy_entity).insert(patrol.clone());
+    }
+    // 보스는 전투 구역에 들어오면 바로 싸우므로 시야 판정 없음
+    match &definition.enemy.boss_id {
+        Some(boss_id) => {
+            commands.entity(enemy_entity).insert(BossState::new(boss_id, definition.position));
+        }
+        None => {
+            commands.entity(enemy_entity).insert(Perception::with_sight_range(definition.enemy.detection_range));
+            // 머리 위 경계도 표시 (색은 적마다 따로 바뀌므로 재질도 따로)
+            commands.entity(enemy_entity).with_child((
+                Mesh3d(meshes.add(Sphere::new(0.12))),
+                MeshMaterial3d(materials.add(StandardMaterial {
+                    base_color: Color::srgb(1.0, 0.85, 0.2),
+                    unlit: true,
+                    ..default()
+                })),
+                Transform::from_translation(Vec3::Y * 1.9),
+                Visibility::Hidden,
+                AwarenessIndicator,
+            ));
+        }
+    }
+    enemy_entity
+}
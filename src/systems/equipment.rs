@@ -0,0 +1,442 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use std::collections::HashMap;
+use crate::components::*;
+use crate::resources::*;
+use crate::states::GameState;
+use crate::systems::third_person_camera::ThirdPersonCamera;
+use crate::systems::ui::{menu_root, set_menu_cursor, MENU_ENTRY_COLOR, MENU_SELECTED_COLOR};
+
+// 방어구를 입지 않았을 때의 강인도
+const BASE_POISE: f32 = 30.0;
+// 손에 든 모델을 붙일 뼈대 이름 - Aurora (UE 스켈레톤) / main_skeleton 순으로 찾음
+const RIGHT_HAND_BONES: [&str; 3] = ["weapon_r", "hand_r", "EquipmentHandle.R"];
+const LEFT_HAND_BONES: [&str; 3] = ["weapon_l", "hand_l", "Wrist.L"];
+
+pub fn load_item_database(mut item_db: ResMut<ItemDatabase>) {
+    match std::fs::read_to_string(ITEM_DATA_PATH) {
+        Ok(json) => match serde_json::from_str::<HashMap<String, ItemDefinition>>(&json) {
+            Ok(items) => {
+                info!("Loaded {} items from {}", items.len(), ITEM_DATA_PATH);
+                item_db.items.extend(items);
+            }
+            Err(e) => error!("Failed to parse item JSON: {}", e),
+        },
+        Err(e) => warn!("Failed to read item file, no items available: {}", e),
+    }
+}
+
+// 오른손이 비었을 때 - 맨손 공격 (모션 데이터가 없으므로 단일 공격)
+fn unarmed_weapon() -> Weapon {
+    Weapon {
+        damage: 8.0,
+        damage_type: DamageType::Strike,
+        attack_range: 1.2,
+        attack_cooldown: 0.6,
+        stamina_cost: 10.0,
+        moveset_id: "unarmed".to_string(),
+        guard_absorption: 0.2,
+        guard_stability: 0.1,
+        strength_scaling: 0.2,
+        dexterity_scaling: 0.2,
+        durability: 0.0,
+        max_durability: 0.0,
+        ..default()
+    }
+}
+
+// 장착한 아이템 무게 합 (예비 무기 포함)
+pub fn equipped_weight(equipment: &Equipment, item_db: &ItemDatabase) -> f32 {
+    equipment
+        .equipped_ids()
+        .filter_map(|item_id| item_db.get(item_id))
+        .map(|item| item.weight)
+        .sum()
+}
+
+// 장비가 바뀌면 무기 / 가드 / 중량 / 강인도 / 피해 감소율 / 반지 배율 / 손에 든 모델 갱신
+pub fn apply_equipment(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    item_db: Res<ItemDatabase>,
+    mut player_query: Query<(
+        Entity,
+        &Equipment,
+        &mut Weapon,
+        &mut EquipLoad,
+        &mut Poise,
+        &mut Resistances,
+        &mut EquipmentBonuses,
+    ), (With<Player>, Changed<Equipment>)>,
+    held_query: Query<(Entity, &HeldItemModel)>,
+) {
+    for (player_entity, equipment, mut weapon, mut equip_load, mut poise, mut resistances, mut bonuses) in player_query.iter_mut() {
+        let right = equipment.active_right_hand().and_then(|item_id| item_db.get(item_id));
+        let left = equipment.active_left_hand().and_then(|item_id| item_db.get(item_id));
+
+        // 오른손 무기 - 모션 / 애니메이션 세트도 무기 데이터를 따름
+        let mut new_weapon = match right.map(|item| &item.kind) {
+            Some(ItemKind::Weapon(data)) => data.to_weapon(),
+            _ => unarmed_weapon(),
+        };
+
+        // 왼손 방패가 있으면 가드는 방패로 받음
+        if let Some(ItemKind::Shield(shield)) = left.map(|item| &item.kind) {
+            new_weapon.guard_absorption = shield.guard_absorption;
+            new_weapon.guard_stability = shield.guard_stability;
+        }
+        *weapon = new_weapon;
+
+        equip_load.current = equipped_weight(equipment, &item_db);
+
+        // 방어구 강인도 / 피해 감소율은 부위별 합산
+        let mut armor_poise = 0.0;
+        let mut absorption: HashMap<DamageType, f32> = HashMap::new();
+        for slot in ArmorSlot::ALL {
+            let Some(item) = equipment.get(EquipSlot::Armor(slot)).and_then(|item_id| item_db.get(item_id)) else {
+                continue;
+            };
+            if let ItemKind::Armor(armor) = &item.kind {
+                armor_poise += armor.poise;
+                for (damage_type, value) in &armor.absorption {
+                    *absorption.entry(*damage_type).or_insert(0.0) += value;
+                }
+            }
+        }
+        let max_poise = BASE_POISE + armor_poise;
+        poise.current = (poise.current + max_poise - poise.max).clamp(0.0, max_poise);
+        poise.max = max_poise;
+        resistances.damage = absorption;
+
+        // 반지 배율은 곱연산
+        let mut new_bonuses = EquipmentBonuses::default();
+        for index in 0..equipment.rings.len() {
+            let Some(item) = equipment.get(EquipSlot::Ring(index)).and_then(|item_id| item_db.get(item_id)) else {
+                continue;
+            };
+            if let ItemKind::Ring(ring) = &item.kind {
+                new_bonuses.max_health_scale *= ring.max_health_scale;
+                new_bonuses.max_stamina_scale *= ring.max_stamina_scale;
+                new_bonuses.equip_load_scale *= ring.equip_load_scale;
+            }
+        }
+        *bonuses = new_bonuses;
+
+        // 손에 든 모델 교체
+        for (held_entity, held) in held_query.iter() {
+            if held.owner == player_entity {
+                commands.entity(held_entity).despawn_recursive();
+            }
+        }
+        for (hand, item) in [(Hand::Right, right), (Hand::Left, left)] {
+            let model = match item.map(|item| &item.kind) {
+                Some(ItemKind::Weapon(data)) => data.model.as_ref().map(|path| (path, data.model_scale)),
+                Some(ItemKind::Shield(data)) => data.model.as_ref().map(|path| (path, data.model_scale)),
+                _ => None,
+            };
+            let Some((path, model_scale)) = model else {
+                continue;
+            };
+
+            // 캐릭터 모델이 뜨기 전까지는 손 위치 근사 - 왼손은 오른손 위치를 좌우 반전
+            let side = if hand == Hand::Right { 1.0 } else { -1.0 };
+            let scene = asset_server.load(GltfAssetLabel::Scene(0).from_asset(path.clone()));
+            commands.entity(player_entity).with_children(|parent| {
+                parent.spawn((
+                    SceneRoot(scene),
+                    Transform::from_xyz(0.3 * side, 0.5, 0.1)
+                        .with_scale(Vec3::splat(0.05 * model_scale))
+                        .with_rotation(Quat::from_euler(EulerRot::XYZ,
+                            -std::f32::consts::FRAC_PI_2,
+                            0.0,
+                            std::f32::consts::FRAC_PI_4 * side)),
+                    HeldItemModel { owner: player_entity, hand },
+                    Name::new(format!("{:?}HandItem", hand)),
+                ));
+            });
+        }
+
+        info!(
+            "Equipment updated: {} / {} (load {:.1}, poise {:.0})",
+            equipment.active_right_hand().map_or("Unarmed", |item_id| item_db.name(item_id)),
+            equipment.active_left_hand().map_or("Empty", |item_id| item_db.name(item_id)),
+            equip_load.current,
+            poise.max,
+        );
+    }
+}
+
+// 손에 든 모델을 캐릭터 모델의 손 뼈대로 옮김 - 애니메이션을 따라 움직임
+// 뼈대 기준으로 쥐므로 위치/회전 근사는 버리고, 화면상 크기만 유지하도록 뼈대까지의 배율을 되돌림
+pub fn attach_held_item_models(
+    mut commands: Commands,
+    held_query: Query<(Entity, &HeldItemModel, &Parent, &Transform)>,
+    children_query: Query<&Children>,
+    name_query: Query<&Name>,
+    parent_query: Query<&Parent>,
+    transform_query: Query<&Transform>,
+) {
+    for (held_entity, held, parent, transform) in held_query.iter() {
+        // 이미 뼈대에 붙어 있음
+        if parent.get() != held.owner {
+            continue;
+        }
+
+        let bone_names = match held.hand {
+            Hand::Right => &RIGHT_HAND_BONES,
+            Hand::Left => &LEFT_HAND_BONES,
+        };
+        // 모델 씬이 아직 생성되지 않았으면 다음 프레임에 다시 시도
+        let Some(bone) = bone_names
+            .iter()
+            .find_map(|bone_name| find_named_descendant(held.owner, bone_name, &children_query, &name_query))
+        else {
+            continue;
+        };
+
+        let mut bone_scale = Vec3::ONE;
+        let mut current = bone;
+        while current != held.owner {
+            bone_scale *= transform_query.get(current).map_or(Vec3::ONE, |transform| transform.scale);
+            let Ok(next) = parent_query.get(current) else {
+                break;
+            };
+            current = next.get();
+        }
+
+        commands.entity(held_entity).set_parent(bone).insert(Transform::from_scale(transform.scale / bone_scale));
+        info!("Attached {:?} hand item to bone {:?}", held.hand, name_query.get(bone).map(|name| name.as_str()));
+    }
+}
+
+fn find_named_descendant(
+    entity: Entity,
+    name: &str,
+    children_query: &Query<&Children>,
+    name_query: &Query<&Name>,
+) -> Option<Entity> {
+    let children = children_query.get(entity).ok()?;
+    for &child in children.iter() {
+        if name_query.get(child).is_ok_and(|child_name| child_name.as_str() == name) {
+            return Some(child);
+        }
+        if let Some(found) = find_named_descendant(child, name, children_query, name_query) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+// 장비 메뉴 열기 - 다른 동작 중에는 열 수 없음
+pub fn open_equipment_menu(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    input_mapping: Res<InputMapping>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut equipment_menu: ResMut<EquipmentMenuState>,
+    player_query: Query<(&Player, &Health, &Poise, &AttackState, &GuardState, &CriticalAttackState, &HealingFlask)>,
+) {
+    if !keyboard_input.just_pressed(input_mapping.inventory) {
+        return;
+    }
+
+    let Ok((player, health, poise, attack_state, guard_state, critical_state, flask)) = player_query.get_single() else {
+        return;
+    };
+    let busy = health.current <= 0.0
+        || player.is_rolling
+        || attack_state.is_attacking
+        || attack_state.is_charging
+        || guard_state.is_guard_broken()
+        || critical_state.is_active()
+        || poise.is_staggered()
+        || flask.is_drinking();
+    if busy {
+        return;
+    }
+
+    equipment_menu.selected = 0;
+    next_state.set(GameState::Inventory);
+}
+
+pub fn setup_equipment_menu(
+    mut commands: Commands,
+    mut window_q: Query<&mut Window, With<PrimaryWindow>>,
+    mut cam_q: Query<&mut ThirdPersonCamera>,
+) {
+    set_menu_cursor(&mut window_q, &mut cam_q, true);
+
+    commands.spawn((
+        menu_root(),
+        EquipmentMenu,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new("EQUIPMENT"),
+            TextFont {
+                font_size: 48.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+
+        parent.spawn((
+            Text::new(""),
+            TextFont {
+                font_size: 22.0,
+                ..default()
+            },
+            TextColor(Color::srgb(1.0, 1.0, 0.0)),
+            Node {
+                margin: UiRect::bottom(Val::Px(12.0)),
+                ..default()
+            },
+            EquipmentSummary,
+        ));
+
+        for index in 0..EquipSlot::ALL.len() {
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(MENU_ENTRY_COLOR),
+                EquipmentEntry(index),
+            ));
+        }
+
+        parent.spawn((
+            Text::new(""),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.8, 0.8, 0.8)),
+            Node {
+                margin: UiRect::top(Val::Px(16.0)),
+                ..default()
+            },
+            EquipmentItemList,
+        ));
+
+        parent.spawn((
+            Text::new("W/S to select slot, A/D to change item, ESC to close"),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.6, 0.6, 0.6)),
+            Node {
+                margin: UiRect::top(Val::Px(24.0)),
+                ..default()
+            },
+        ));
+    });
+}
+
+// 장비 메뉴 조작 - 선택한 칸에 넣을 수 있는 소지품을 차례로 바꿔 끼움 (빈 칸 포함)
+pub fn equipment_menu_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    input_mapping: Res<InputMapping>,
+    item_db: Res<ItemDatabase>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut equipment_menu: ResMut<EquipmentMenuState>,
+    mut player_query: Query<(&Inventory, &mut Equipment, &EquipLoad), With<Player>>,
+    mut summary_query: Query<&mut Text, (With<EquipmentSummary>, Without<EquipmentEntry>, Without<EquipmentItemList>)>,
+    mut item_list_query: Query<&mut Text, (With<EquipmentItemList>, Without<EquipmentEntry>, Without<EquipmentSummary>)>,
+    mut entry_query: Query<(&EquipmentEntry, &mut Text, &mut TextColor), (Without<EquipmentSummary>, Without<EquipmentItemList>)>,
+) {
+    if keyboard_input.just_pressed(input_mapping.pause) || keyboard_input.just_pressed(input_mapping.inventory) {
+        next_state.set(GameState::Playing);
+        return;
+    }
+
+    let Ok((inventory, mut equipment, equip_load)) = player_query.get_single_mut() else {
+        return;
+    };
+
+    let count = EquipSlot::ALL.len();
+    if keyboard_input.any_just_pressed([input_mapping.move_forward, KeyCode::ArrowUp]) {
+        equipment_menu.selected = (equipment_menu.selected + count - 1) % count;
+    }
+    if keyboard_input.any_just_pressed([input_mapping.move_backward, KeyCode::ArrowDown]) {
+        equipment_menu.selected = (equipment_menu.selected + 1) % count;
+    }
+
+    let step: isize = if keyboard_input.any_just_pressed([input_mapping.move_left, KeyCode::ArrowLeft]) {
+        -1
+    } else if keyboard_input.any_just_pressed([input_mapping.move_right, KeyCode::ArrowRight]) {
+        1
+    } else {
+        0
+    };
+    if step != 0 {
+        let slot = EquipSlot::ALL[equipment_menu.selected];
+        let mut candidates: Vec<Option<String>> = vec![None];
+        candidates.extend(
+            inventory
+                .items
+                .iter()
+                .filter(|item| item.quantity > 0)
+                .filter(|item| item_db.get(&item.item_id).is_some_and(|def| slot.accepts(&def.kind)))
+                .filter(|item| !equipment.is_equipped_elsewhere(&item.item_id, slot))
+                .map(|item| Some(item.item_id.clone())),
+        );
+
+        let current = equipment.get(slot).cloned();
+        let index = candidates.iter().position(|candidate| *candidate == current).unwrap_or(0);
+        let next = (index as isize + step).rem_euclid(candidates.len() as isize) as usize;
+        if candidates[next] != current {
+            equipment.set(slot, candidates[next].clone());
+        }
+    }
+
+    // 메뉴 안에서는 apply_equipment 가 돌지 않으므로 무게는 직접 계산
+    for mut text in summary_query.iter_mut() {
+        text.0 = format!("Equip Load {:.1} / {:.1}", equipped_weight(&equipment, &item_db), equip_load.max);
+    }
+
+    for mut text in item_list_query.iter_mut() {
+        let items: Vec<String> = inventory
+            .items
+            .iter()
+            .filter(|item| {
+                item_db
+                    .get(&item.item_id)
+                    .is_some_and(|def| matches!(def.kind, ItemKind::Consumable | ItemKind::KeyItem | ItemKind::UpgradeMaterial))
+            })
+            .map(|item| format!("{} x{}", item_db.name(&item.item_id), item.quantity))
+            .collect();
+        text.0 = if items.is_empty() {
+            "Items: -".to_string()
+        } else {
+            format!("Items: {}", items.join(", "))
+        };
+    }
+
+    for (entry, mut text, mut color) in entry_query.iter_mut() {
+        let slot = EquipSlot::ALL[entry.0];
+        // 현재 손에 든 무기 표시
+        let active = match slot {
+            EquipSlot::RightHand(index) => index == equipment.active_right,
+            EquipSlot::LeftHand(index) => index == equipment.active_left,
+            _ => false,
+        };
+        let name = equipment.get(slot).map_or("-", |item_id| item_db.name(item_id));
+        text.0 = format!("{}{:<12} {}", if active { "* " } else { "  " }, slot.label(), name);
+        let selected = equipment_menu.selected == entry.0;
+        color.0 = if selected { MENU_SELECTED_COLOR } else { MENU_ENTRY_COLOR };
+    }
+}
+
+pub fn cleanup_equipment_menu(
+    mut commands: Commands,
+    menu_query: Query<Entity, With<EquipmentMenu>>,
+    mut window_q: Query<&mut Window, With<PrimaryWindow>>,
+    mut cam_q: Query<&mut ThirdPersonCamera>,
+) {
+    set_menu_cursor(&mut window_q, &mut cam_q, false);
+
+    for entity in menu_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -0,0 +1,316 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_rapier3d::prelude::*;
+use std::f32::consts::FRAC_PI_4;
+use crate::components::*;
+use crate::resources::*;
+use crate::states::GameState;
+use crate::systems::checkpoint::{nearest_lit_checkpoint, restore_player_stats, CHECKPOINT_RESPAWN_OFFSET};
+use crate::systems::third_person_camera::ThirdPersonCamera;
+use crate::systems::ui::{menu_root, set_menu_cursor, MENU_ENTRY_COLOR, MENU_SELECTED_COLOR};
+
+// 로딩 화면 최소 표시 시간 (도착 지점 물리가 안정될 때까지)
+const FAST_TRAVEL_LOADING_TIME: f32 = 1.5;
+// 도착 시 체크포인트에서 떨어진 거리
+const FAST_TRAVEL_PLACEMENT_RADIUS: f32 = 2.5;
+// 발밑 지면 탐색 - 후보 지점 위에서 아래로
+const FAST_TRAVEL_PROBE_HEIGHT: f32 = 3.0;
+const FAST_TRAVEL_PROBE_DEPTH: f32 = 10.0;
+// 캡슐 중심 높이 (capsule_y(1.0, 0.5))
+const PLAYER_GROUND_CLEARANCE: f32 = 1.5;
+
+// 점화한 체크포인트 근처에서 이동 키 - 다른 점화 체크포인트가 있으면 메뉴 열기
+pub fn open_fast_travel_menu(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    input_mapping: Res<InputMapping>,
+    mut fast_travel: ResMut<FastTravel>,
+    mut next_state: ResMut<NextState<GameState>>,
+    player_query: Query<(&Transform, &Health), With<Player>>,
+    checkpoint_query: Query<(Entity, &GlobalTransform, &Checkpoint)>,
+) {
+    if !keyboard_input.just_pressed(input_mapping.travel) {
+        return;
+    }
+
+    let Ok((player_transform, health)) = player_query.get_single() else {
+        return;
+    };
+    if health.current <= 0.0 {
+        return;
+    }
+
+    let Some(current) = nearest_lit_checkpoint(checkpoint_query.iter(), player_transform.translation) else {
+        info!("Fast travel is only available at a lit checkpoint");
+        return;
+    };
+
+    // 지역 순서, 같은 지역 안에서는 이름 순
+    let mut destinations: Vec<(Entity, &Checkpoint)> = checkpoint_query
+        .iter()
+        .filter(|(entity, _, checkpoint)| *entity != current && checkpoint.lit)
+        .map(|(entity, _, checkpoint)| (entity, checkpoint))
+        .collect();
+    destinations.sort_by(|a, b| (a.1.zone, &a.1.id).cmp(&(b.1.zone, &b.1.id)));
+
+    if destinations.is_empty() {
+        info!("No other lit checkpoints to travel to");
+        return;
+    }
+
+    fast_travel.destinations = destinations.into_iter().map(|(entity, _)| entity).collect();
+    fast_travel.selected = 0;
+    fast_travel.destination = None;
+    next_state.set(GameState::FastTravel);
+}
+
+// 이동 가능한 체크포인트를 지역별로 묶어서 표시
+pub fn setup_fast_travel_menu(
+    mut commands: Commands,
+    fast_travel: Res<FastTravel>,
+    checkpoint_query: Query<&Checkpoint>,
+    mut window_q: Query<&mut Window, With<PrimaryWindow>>,
+    mut cam_q: Query<&mut ThirdPersonCamera>,
+) {
+    set_menu_cursor(&mut window_q, &mut cam_q, true);
+
+    commands.spawn((
+        menu_root(),
+        FastTravelMenu,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new("TRAVEL"),
+            TextFont {
+                font_size: 48.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+
+        let mut current_zone = None;
+        for (index, &entity) in fast_travel.destinations.iter().enumerate() {
+            let Ok(checkpoint) = checkpoint_query.get(entity) else {
+                continue;
+            };
+
+            // 지역이 바뀔 때마다 지역 이름 표시
+            if current_zone != Some(checkpoint.zone) {
+                current_zone = Some(checkpoint.zone);
+                parent.spawn((
+                    Text::new(checkpoint.zone.display_name()),
+                    TextFont {
+                        font_size: 28.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                    Node {
+                        margin: UiRect::top(Val::Px(12.0)),
+                        ..default()
+                    },
+                ));
+            }
+
+            parent.spawn((
+                Text::new(checkpoint.id.clone()),
+                TextFont {
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(if index == fast_travel.selected { MENU_SELECTED_COLOR } else { MENU_ENTRY_COLOR }),
+                FastTravelEntry(index),
+            ));
+        }
+
+        parent.spawn((
+            Text::new("W/S to select, ENTER to travel, ESC to cancel"),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.6, 0.6, 0.6)),
+            Node {
+                margin: UiRect::top(Val::Px(24.0)),
+                ..default()
+            },
+        ));
+    });
+}
+
+// 메뉴 조작 - 선택 이동, 확정 시 로딩 상태로 전환
+pub fn fast_travel_menu_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    input_mapping: Res<InputMapping>,
+    mut fast_travel: ResMut<FastTravel>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut entry_query: Query<(&FastTravelEntry, &mut TextColor)>,
+) {
+    if keyboard_input.just_pressed(input_mapping.pause) || keyboard_input.just_pressed(input_mapping.travel) {
+        next_state.set(GameState::Playing);
+        return;
+    }
+
+    let count = fast_travel.destinations.len();
+    if count == 0 {
+        next_state.set(GameState::Playing);
+        return;
+    }
+
+    if keyboard_input.any_just_pressed([input_mapping.move_forward, KeyCode::ArrowUp]) {
+        fast_travel.selected = (fast_travel.selected + count - 1) % count;
+    }
+    if keyboard_input.any_just_pressed([input_mapping.move_backward, KeyCode::ArrowDown]) {
+        fast_travel.selected = (fast_travel.selected + 1) % count;
+    }
+
+    for (entry, mut color) in entry_query.iter_mut() {
+        color.0 = if entry.0 == fast_travel.selected { MENU_SELECTED_COLOR } else { MENU_ENTRY_COLOR };
+    }
+
+    if keyboard_input.just_pressed(KeyCode::Enter) {
+        fast_travel.destination = Some(fast_travel.destinations[fast_travel.selected]);
+        fast_travel.loading_timer = 0.0;
+        next_state.set(GameState::Loading);
+    }
+}
+
+pub fn cleanup_fast_travel_menu(
+    mut commands: Commands,
+    menu_query: Query<Entity, With<FastTravelMenu>>,
+    mut window_q: Query<&mut Window, With<PrimaryWindow>>,
+    mut cam_q: Query<&mut ThirdPersonCamera>,
+) {
+    set_menu_cursor(&mut window_q, &mut cam_q, false);
+
+    for entity in menu_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+// 로딩 시작 - 로딩 화면을 띄우고 플레이어를 도착 체크포인트 옆 안전한 자리로 옮김
+pub fn begin_fast_travel(
+    mut commands: Commands,
+    fast_travel: Res<FastTravel>,
+    mut respawn_point: ResMut<RespawnPoint>,
+    rapier_context: ReadDefaultRapierContext,
+    mut player_query: Query<(Entity, &mut Transform, &mut Velocity, &mut LockOnState), With<Player>>,
+    checkpoint_query: Query<(&GlobalTransform, &Checkpoint)>,
+) {
+    let Some(destination) = fast_travel.destination else {
+        return;
+    };
+    let Ok((checkpoint_transform, checkpoint)) = checkpoint_query.get(destination) else {
+        warn!("Fast travel destination {:?} no longer exists", destination);
+        return;
+    };
+
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(Color::BLACK),
+        GlobalZIndex(20),
+        LoadingScreen,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(format!("Traveling to {}...", checkpoint.id)),
+            TextFont {
+                font_size: 32.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+    });
+
+    let Ok((player_entity, mut transform, mut velocity, mut lock_on)) = player_query.get_single_mut() else {
+        return;
+    };
+
+    let position = find_safe_position(
+        rapier_context.single(),
+        player_entity,
+        destination,
+        checkpoint_transform.translation(),
+    );
+    transform.translation = position;
+    velocity.linvel = Vec3::ZERO;
+    lock_on.target = None;
+
+    // 도착한 체크포인트에서 휴식한 것으로 취급
+    respawn_point.position = position;
+    respawn_point.checkpoint = Some(destination);
+
+    info!("Traveling to {} ({})", checkpoint.id, checkpoint.zone.display_name());
+}
+
+// 로딩 시간이 지나면 휴식 처리 후 게임으로 복귀
+pub fn fast_travel_loading_system(
+    time: Res<Time>,
+    mut fast_travel: ResMut<FastTravel>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut reset_events: EventWriter<WorldResetEvent>,
+    mut player_query: Query<(&mut Health, &mut Stamina, &mut Poise, &mut StatusBuildup), With<Player>>,
+) {
+    fast_travel.loading_timer += time.delta_secs();
+    if fast_travel.loading_timer < FAST_TRAVEL_LOADING_TIME {
+        return;
+    }
+
+    if fast_travel.destination.take().is_some() {
+        if let Ok((mut health, mut stamina, mut poise, mut status)) = player_query.get_single_mut() {
+            restore_player_stats(&mut health, &mut stamina, &mut poise, &mut status);
+        }
+        reset_events.send(WorldResetEvent);
+    }
+
+    next_state.set(GameState::Playing);
+}
+
+pub fn cleanup_loading_screen(
+    mut commands: Commands,
+    loading_query: Query<Entity, With<LoadingScreen>>,
+) {
+    for entity in loading_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+// 체크포인트 둘레를 45도씩 돌며 막히지 않고 발밑에 지면이 있는 자리를 찾음
+fn find_safe_position(
+    rapier_context: &RapierContext,
+    player_entity: Entity,
+    checkpoint_entity: Entity,
+    checkpoint_position: Vec3,
+) -> Vec3 {
+    let filter = QueryFilter::default()
+        .exclude_rigid_body(player_entity)
+        .exclude_collider(checkpoint_entity)
+        .exclude_sensors();
+
+    for step in 0..8 {
+        // 체크포인트 정면(+Z)부터 시도
+        let direction = Quat::from_rotation_y(step as f32 * FRAC_PI_4) * Vec3::Z;
+
+        // 체크포인트에서 후보 지점까지 가로막는 것이 없는지
+        if rapier_context
+            .cast_ray(checkpoint_position, direction, FAST_TRAVEL_PLACEMENT_RADIUS, true, filter)
+            .is_some()
+        {
+            continue;
+        }
+
+        let probe_origin = checkpoint_position + direction * FAST_TRAVEL_PLACEMENT_RADIUS + Vec3::Y * FAST_TRAVEL_PROBE_HEIGHT;
+        match rapier_context.cast_ray(probe_origin, Vec3::NEG_Y, FAST_TRAVEL_PROBE_DEPTH, true, filter) {
+            // 탐색 시작점이 이미 무언가의 안이면 건너뜀
+            Some((_, toi)) if toi > 0.0 => {
+                return probe_origin + Vec3::NEG_Y * toi + Vec3::Y * PLAYER_GROUND_CLEARANCE;
+            }
+            _ => continue,
+        }
+    }
+
+    warn!("No safe ground found around checkpoint, using default offset");
+    checkpoint_position + CHECKPOINT_RESPAWN_OFFSET
+}
@@ -0,0 +1,104 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::resources::*;
+
+// 회복 물약 마시기 - 시작 조건 확인, 회복 시점에 체력 회복, 경직 시 끊김
+pub fn flask_system(
+    time: Res<Time>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    input_mapping: Res<InputMapping>,
+    mut player_query: Query<(&mut HealingFlask, &mut Health, &Player, &Poise, &AttackState, &GuardState, &ParryState, &CriticalAttackState), With<Player>>,
+) {
+    let Ok((mut flask, mut health, player, poise, attack_state, guard_state, parry_state, critical_state)) = player_query.get_single_mut() else {
+        return;
+    };
+    if health.current <= 0.0 {
+        flask.interrupt();
+        return;
+    }
+
+    if flask.is_drinking() {
+        // 강인도 경직 / 가드 브레이크면 회복 전에 끊김
+        if poise.is_staggered() || guard_state.is_guard_broken() {
+            flask.interrupt();
+            info!("Flask interrupted");
+            return;
+        }
+
+        flask.drink_timer -= time.delta_secs();
+        let elapsed = flask.drink_duration - flask.drink_timer;
+        if !flask.healed && elapsed >= flask.heal_delay {
+            flask.healed = true;
+            health.current = (health.current + flask.heal_amount()).min(health.max);
+            info!("Flask healed {} (health {}), {} charges left", flask.heal_amount(), health.current, flask.charges);
+        }
+        return;
+    }
+
+    if !keyboard_input.just_pressed(input_mapping.use_item) {
+        return;
+    }
+
+    let can_drink = !player.is_rolling
+        && !attack_state.is_attacking
+        && !attack_state.is_charging
+        && !guard_state.is_guarding
+        && !guard_state.is_guard_broken()
+        && !parry_state.is_parrying()
+        && !critical_state.is_active()
+        && !poise.is_staggered();
+    if !can_drink {
+        return;
+    }
+
+    if !flask.start_drinking() {
+        info!("Flask is empty");
+    }
+}
+
+// 체크포인트 휴식 / 부활 / 빠른 이동 시 회복 물약 다시 채움
+pub fn flask_refill_system(
+    mut reset_events: EventReader<WorldResetEvent>,
+    mut flask_query: Query<&mut HealingFlask, With<Player>>,
+) {
+    if reset_events.read().count() == 0 {
+        return;
+    }
+
+    for mut flask in flask_query.iter_mut() {
+        flask.refill();
+    }
+}
+
+// 회복 물약 강화 - 최대 횟수는 바로 채워 주고, 회복량은 다음 사용부터 적용
+pub fn flask_upgrade_system(
+    mut upgrade_events: EventReader<FlaskUpgradeEvent>,
+    mut flask_query: Query<&mut HealingFlask, With<Player>>,
+) {
+    let Ok(mut flask) = flask_query.get_single_mut() else {
+        return;
+    };
+
+    for event in upgrade_events.read() {
+        apply_flask_upgrade(&mut flask, event.upgrade);
+    }
+}
+
+// 상점처럼 Playing 밖에서 얻은 강화도 바로 적용할 수 있도록 분리
+pub fn apply_flask_upgrade(flask: &mut HealingFlask, upgrade: FlaskUpgrade) {
+    if !flask.can_upgrade(upgrade) {
+        info!("Flask {:?} is already at its limit", upgrade);
+        return;
+    }
+    match upgrade {
+        FlaskUpgrade::Charges => {
+            flask.max_charges += 1;
+            flask.charges += 1;
+            info!("Flask charges upgraded to {}", flask.max_charges);
+        }
+        FlaskUpgrade::Potency => {
+            flask.potency_level += 1;
+            info!("Flask potency upgraded to +{} (heals {})", flask.potency_level, flask.heal_amount());
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use crate::components::*;
+use crate::resources::*;
+use crate::states::GameState;
+use crate::systems::checkpoint::nearest_lit_checkpoint;
+use crate::systems::third_person_camera::ThirdPersonCamera;
+use crate::systems::ui::{menu_root, set_menu_cursor, MENU_ENTRY_COLOR, MENU_SELECTED_COLOR};
+
+// 모든 능력치의 시작값 - 이 값에서 파생 수치가 기본값이 됨
+const BASE_ATTRIBUTE: u32 = 10;
+
+// 능력치 구간별 1포인트당 증가량 - 소프트캡을 넘으면 효율이 떨어짐
+fn soft_capped(value: u32, steps: &[(u32, f32)]) -> f32 {
+    let mut total = 0.0;
+    let mut from = BASE_ATTRIBUTE;
+    for &(cap, per_point) in steps {
+        if value <= from {
+            break;
+        }
+        total += (value.min(cap) - from) as f32 * per_point;
+        from = cap;
+    }
+    total
+}
+
+pub fn max_health_for(stats: &CharacterStats) -> f32 {
+    100.0 + soft_capped(stats.vigor, &[(30, 8.0), (50, 4.0), (CharacterStats::MAX_ATTRIBUTE, 1.0)])
+}
+
+pub fn max_stamina_for(stats: &CharacterStats) -> f32 {
+    100.0 + soft_capped(stats.endurance, &[(40, 3.0), (CharacterStats::MAX_ATTRIBUTE, 1.0)])
+}
+
+pub fn stamina_regen_for(stats: &CharacterStats) -> f32 {
+    20.0 + soft_capped(stats.endurance, &[(40, 0.5)])
+}
+
+pub fn max_equip_load_for(stats: &CharacterStats) -> f32 {
+    60.0 + soft_capped(stats.vitality, &[(40, 1.5), (CharacterStats::MAX_ATTRIBUTE, 0.5)])
+}
+
+// 무기 보정 - 보정 계수 × 능력치 배율만큼 기본 데미지에 더해짐
+pub fn weapon_attribute_bonus(weapon: &Weapon, stats: &CharacterStats) -> f32 {
+    let curve = [(40, 0.025), (60, 0.01), (CharacterStats::MAX_ATTRIBUTE, 0.0025)];
+    let strength = soft_capped(stats.strength, &curve);
+    let dexterity = soft_capped(stats.dexterity, &curve);
+    weapon.damage * (weapon.strength_scaling * strength + weapon.dexterity_scaling * dexterity)
+}
+
+// 능력치나 반지 배율이 바뀌면 체력/스테미나/장비 중량 최대치 재계산 (현재치는 늘어난 만큼 함께 증가)
+pub fn apply_character_stats(
+    mut player_query: Query<
+        (&CharacterStats, &EquipmentBonuses, &mut Health, &mut Stamina, &mut EquipLoad),
+        Or<(Changed<CharacterStats>, Changed<EquipmentBonuses>)>,
+    >,
+) {
+    for (stats, bonuses, mut health, mut stamina, mut equip_load) in player_query.iter_mut() {
+        let max_health = max_health_for(stats) * bonuses.max_health_scale;
+        health.current = (health.current + max_health - health.max).clamp(0.0, max_health);
+        health.max = max_health;
+
+        let max_stamina = max_stamina_for(stats) * bonuses.max_stamina_scale;
+        stamina.current = (stamina.current + max_stamina - stamina.max).clamp(0.0, max_stamina);
+        stamina.max = max_stamina;
+        stamina.regen_rate = stamina_regen_for(stats);
+
+        equip_load.max = max_equip_load_for(stats) * bonuses.equip_load_scale;
+    }
+}
+
+// 능력치나 무기가 바뀌면 무기 보정 재계산
+pub fn apply_weapon_scaling(
+    mut player_query: Query<(&CharacterStats, &mut Weapon), Or<(Changed<CharacterStats>, Changed<Weapon>)>>,
+) {
+    for (stats, mut weapon) in player_query.iter_mut() {
+        let bonus = weapon_attribute_bonus(&weapon, stats);
+        // 값이 같으면 쓰지 않음 (Changed<Weapon> 이 매 프레임 다시 걸리지 않도록)
+        if (weapon.attribute_bonus - bonus).abs() > f32::EPSILON {
+            weapon.attribute_bonus = bonus;
+            info!("Weapon attribute bonus: +{:.1}", bonus);
+        }
+    }
+}
+
+// 점화한 체크포인트 근처에서 레벨업 키 - 레벨업 메뉴 열기
+pub fn open_level_up_menu(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    input_mapping: Res<InputMapping>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut level_up_menu: ResMut<LevelUpMenuState>,
+    player_query: Query<(&Transform, &Health), With<Player>>,
+    checkpoint_query: Query<(Entity, &GlobalTransform, &Checkpoint)>,
+) {
+    if !keyboard_input.just_pressed(input_mapping.level_up) {
+        return;
+    }
+
+    let Ok((player_transform, health)) = player_query.get_single() else {
+        return;
+    };
+    if health.current <= 0.0 {
+        return;
+    }
+
+    if nearest_lit_checkpoint(checkpoint_query.iter(), player_transform.translation).is_none() {
+        info!("Level up is only available at a lit checkpoint");
+        return;
+    }
+
+    level_up_menu.selected = 0;
+    next_state.set(GameState::LevelUp);
+}
+
+pub fn setup_level_up_menu(
+    mut commands: Commands,
+    mut window_q: Query<&mut Window, With<PrimaryWindow>>,
+    mut cam_q: Query<&mut ThirdPersonCamera>,
+) {
+    set_menu_cursor(&mut window_q, &mut cam_q, true);
+
+    commands.spawn((
+        menu_root(),
+        LevelUpMenu,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new("LEVEL UP"),
+            TextFont {
+                font_size: 48.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+
+        // 레벨 / 소울 / 비용 (매 프레임 갱신)
+        parent.spawn((
+            Text::new(""),
+            TextFont {
+                font_size: 22.0,
+                ..default()
+            },
+            TextColor(Color::srgb(1.0, 1.0, 0.0)),
+            Node {
+                margin: UiRect::bottom(Val::Px(12.0)),
+                ..default()
+            },
+            LevelUpSummary,
+        ));
+
+        for attribute in Attribute::ALL {
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(MENU_ENTRY_COLOR),
+                LevelUpEntry(attribute),
+            ));
+        }
+
+        parent.spawn((
+            Text::new("W/S to select, ENTER to level up, ESC to close"),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.6, 0.6, 0.6)),
+            Node {
+                margin: UiRect::top(Val::Px(24.0)),
+                ..default()
+            },
+        ));
+    });
+}
+
+// 레벨업 메뉴 조작 - 소울을 써서 선택한 능력치 1 증가
+pub fn level_up_menu_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    input_mapping: Res<InputMapping>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut level_up_menu: ResMut<LevelUpMenuState>,
+    mut player_query: Query<(&mut CharacterStats, &mut Souls), With<Player>>,
+    mut summary_query: Query<&mut Text, (With<LevelUpSummary>, Without<LevelUpEntry>)>,
+    mut entry_query: Query<(&LevelUpEntry, &mut Text, &mut TextColor), Without<LevelUpSummary>>,
+) {
+    if keyboard_input.just_pressed(input_mapping.pause) || keyboard_input.just_pressed(input_mapping.level_up) {
+        next_state.set(GameState::Playing);
+        return;
+    }
+
+    let Ok((mut stats, mut souls)) = player_query.get_single_mut() else {
+        return;
+    };
+
+    let count = Attribute::ALL.len();
+    if keyboard_input.any_just_pressed([input_mapping.move_forward, KeyCode::ArrowUp]) {
+        level_up_menu.selected = (level_up_menu.selected + count - 1) % count;
+    }
+    if keyboard_input.any_just_pressed([input_mapping.move_backward, KeyCode::ArrowDown]) {
+        level_up_menu.selected = (level_up_menu.selected + 1) % count;
+    }
+
+    if keyboard_input.just_pressed(KeyCode::Enter) {
+        let attribute = Attribute::ALL[level_up_menu.selected];
+        let cost = stats.level_up_cost();
+        if souls.count < cost {
+            info!("Not enough souls to level up ({} / {})", souls.count, cost);
+        } else if stats.level_up(attribute) {
+            souls.count -= cost;
+            info!("Leveled up {} to {} (level {})", attribute.display_name(), stats.get(attribute), stats.level);
+        }
+    }
+
+    let cost = stats.level_up_cost();
+    for mut text in summary_query.iter_mut() {
+        text.0 = format!("Level {}    Souls {}    Cost {}", stats.level, souls.count, cost);
+    }
+
+    for (entry, mut text, mut color) in entry_query.iter_mut() {
+        text.0 = format!("{:<10} {:>3}", entry.0.display_name(), stats.get(entry.0));
+        let selected = Attribute::ALL[level_up_menu.selected] == entry.0;
+        color.0 = if selected { MENU_SELECTED_COLOR } else { MENU_ENTRY_COLOR };
+    }
+}
+
+pub fn cleanup_level_up_menu(
+    mut commands: Commands,
+    menu_query: Query<Entity, With<LevelUpMenu>>,
+    mut window_q: Query<&mut Window, With<PrimaryWindow>>,
+    mut cam_q: Query<&mut ThirdPersonCamera>,
+) {
+    set_menu_cursor(&mut window_q, &mut cam_q, false);
+
+    for entity in menu_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -0,0 +1,197 @@
+use bevy::input::mouse::MouseMotion;
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use crate::components::*;
+use crate::resources::*;
+use crate::systems::third_person_camera::ThirdPersonCamera;
+
+// 락온 판정에 사용하는 눈높이
+const LOCK_ON_EYE_HEIGHT: f32 = 0.8;
+// 락온 표시 위치 (대상 머리 위)
+const LOCK_ON_INDICATOR_HEIGHT: f32 = 2.2;
+
+// 락온 대상 위에 떠 있는 표시
+#[derive(Component)]
+pub struct LockOnIndicator;
+
+// 락온 토글, 대상 선택/전환, 거리·시야에 따른 해제
+pub fn lock_on_system(
+    time: Res<Time>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    input_mapping: Res<InputMapping>,
+    mut mouse_motion: EventReader<MouseMotion>,
+    rapier_context: ReadDefaultRapierContext,
+    mut player_query: Query<(Entity, &Transform, &mut LockOnState), With<Player>>,
+    enemy_query: Query<(Entity, &Transform), (With<Enemy>, Without<Dead>, Without<Player>)>,
+    mut camera_query: Query<(&mut ThirdPersonCamera, &Transform), (Without<Player>, Without<Enemy>)>,
+) {
+    let rapier_context = rapier_context.single();
+    let mouse_delta_x: f32 = mouse_motion.read().map(|ev| ev.delta.x).sum();
+
+    let Ok((player_entity, player_transform, mut lock_on)) = player_query.get_single_mut() else {
+        return;
+    };
+    let Ok((mut camera, camera_transform)) = camera_query.get_single_mut() else {
+        return;
+    };
+
+    let player_position = player_transform.translation;
+    let camera_forward = flatten(*camera_transform.forward());
+    let camera_right = flatten(*camera_transform.right());
+
+    // 카메라 정면 기준 대상의 좌우 각도 (오른쪽이 양수)
+    let screen_angle = |position: Vec3| {
+        let to_enemy = flatten(position - player_position);
+        to_enemy.dot(camera_right).atan2(to_enemy.dot(camera_forward))
+    };
+
+    let in_sight = |entity: Entity, position: Vec3| {
+        has_line_of_sight(rapier_context, player_entity, player_position, entity, position)
+    };
+
+    if keyboard_input.just_pressed(input_mapping.lock_on) {
+        if lock_on.is_locked() {
+            lock_on.target = None;
+        } else {
+            // 시야 안의 적 중 화면 중앙에 가깝고 가까운 대상 우선
+            lock_on.target = enemy_query
+                .iter()
+                .filter(|(_, transform)| player_position.distance(transform.translation) <= lock_on.max_distance)
+                .filter(|(_, transform)| screen_angle(transform.translation).abs() <= lock_on.view_angle)
+                .filter(|(entity, transform)| in_sight(*entity, transform.translation))
+                .map(|(entity, transform)| {
+                    let score = screen_angle(transform.translation).abs() / lock_on.view_angle
+                        + player_position.distance(transform.translation) / lock_on.max_distance;
+                    (entity, score)
+                })
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(entity, _)| entity);
+            lock_on.sight_lost_timer = 0.0;
+        }
+    }
+
+    if let Some(target) = lock_on.target {
+        match enemy_query.get(target) {
+            Ok((_, target_transform)) => {
+                let target_position = target_transform.translation;
+
+                if player_position.distance(target_position) > lock_on.break_distance {
+                    info!("Lock-on lost: target too far");
+                    lock_on.target = None;
+                } else if !in_sight(target, target_position) {
+                    lock_on.sight_lost_timer += time.delta_secs();
+                    if lock_on.sight_lost_timer > lock_on.sight_grace {
+                        info!("Lock-on lost: line of sight blocked");
+                        lock_on.target = None;
+                    }
+                } else {
+                    lock_on.sight_lost_timer = 0.0;
+                }
+            }
+            // 대상이 쓰러지거나 사라짐
+            Err(_) => lock_on.target = None,
+        }
+    }
+
+    // 마우스를 좌우로 튕기면 그 방향의 가장 가까운 대상으로 전환
+    lock_on.switch_timer -= time.delta_secs();
+    if let Some(target) = lock_on.target {
+        if mouse_delta_x.abs() >= lock_on.switch_threshold && lock_on.switch_timer <= 0.0 {
+            let current_angle = enemy_query
+                .get(target)
+                .map(|(_, transform)| screen_angle(transform.translation))
+                .unwrap_or(0.0);
+            let flick_sign = mouse_delta_x.signum();
+
+            let next_target = enemy_query
+                .iter()
+                .filter(|(entity, _)| *entity != target)
+                .filter(|(_, transform)| player_position.distance(transform.translation) <= lock_on.max_distance)
+                .map(|(entity, transform)| (entity, transform.translation, screen_angle(transform.translation) - current_angle))
+                .filter(|(_, _, offset)| offset.signum() == flick_sign)
+                .filter(|(entity, position, _)| in_sight(*entity, *position))
+                .min_by(|a, b| a.2.abs().total_cmp(&b.2.abs()))
+                .map(|(entity, ..)| entity);
+
+            if let Some(next_target) = next_target {
+                lock_on.target = Some(next_target);
+                lock_on.sight_lost_timer = 0.0;
+                lock_on.switch_timer = lock_on.switch_cooldown;
+            }
+        }
+    }
+
+    // 카메라가 플레이어와 대상을 함께 잡도록 초점 전달
+    camera.lock_on_focus = lock_on
+        .target
+        .and_then(|target| enemy_query.get(target).ok())
+        .map(|(_, transform)| transform.translation);
+}
+
+// 락온 대상 머리 위에 표시를 띄움
+pub fn lock_on_indicator_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    player_query: Query<&LockOnState, With<Player>>,
+    target_query: Query<&Transform, (With<Enemy>, Without<LockOnIndicator>)>,
+    mut indicator_query: Query<(Entity, &mut Transform), With<LockOnIndicator>>,
+) {
+    let target_position = player_query
+        .get_single()
+        .ok()
+        .and_then(|lock_on| lock_on.target)
+        .and_then(|target| target_query.get(target).ok())
+        .map(|transform| transform.translation + Vec3::Y * LOCK_ON_INDICATOR_HEIGHT);
+
+    match (target_position, indicator_query.get_single_mut()) {
+        (Some(position), Ok((_, mut transform))) => transform.translation = position,
+        (Some(position), Err(_)) => {
+            commands.spawn((
+                Mesh3d(meshes.add(Sphere::new(0.15).mesh().ico(2).unwrap())),
+                MeshMaterial3d(materials.add(StandardMaterial {
+                    base_color: Color::WHITE,
+                    emissive: LinearRgba::new(1.0, 1.0, 1.0, 1.0),
+                    unlit: true,
+                    ..default()
+                })),
+                Transform::from_translation(position),
+                LockOnIndicator,
+            ));
+        }
+        (None, _) => {
+            for (entity, _) in indicator_query.iter() {
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+}
+
+fn flatten(v: Vec3) -> Vec3 {
+    Vec3::new(v.x, 0.0, v.z).normalize_or_zero()
+}
+
+// 플레이어 눈높이에서 대상까지 지형/오브젝트에 가리지 않는지
+fn has_line_of_sight(
+    rapier_context: &RapierContext,
+    player_entity: Entity,
+    from: Vec3,
+    target: Entity,
+    to: Vec3,
+) -> bool {
+    let origin = from + Vec3::Y * LOCK_ON_EYE_HEIGHT;
+    let offset = to + Vec3::Y * LOCK_ON_EYE_HEIGHT - origin;
+    let distance = offset.length();
+    if distance < f32::EPSILON {
+        return true;
+    }
+
+    let filter = QueryFilter::default()
+        .exclude_rigid_body(player_entity)
+        .exclude_sensors();
+
+    match rapier_context.cast_ray(origin, offset / distance, distance, true, filter) {
+        Some((hit_entity, _)) => hit_entity == target,
+        None => true,
+    }
+}
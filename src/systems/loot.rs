@@ -0,0 +1,270 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+use std::collections::HashMap;
+use crate::components::*;
+use crate::resources::*;
+use crate::utils::save::{load_save_file, write_save_file};
+
+// 이 거리 안에서 상호작용 키를 누르면 줍기 / 상자 열기
+pub const PICKUP_INTERACT_RANGE: f32 = 2.0;
+pub const CHEST_INTERACT_RANGE: f32 = 2.5;
+// 적 전리품이 처치 위치 주변에 흩어지는 반경
+const LOOT_SCATTER_RADIUS: f32 = 0.8;
+const CHEST_OPEN_DURATION: f32 = 0.8;
+const CHEST_LID_OPEN_ANGLE: f32 = 1.9;  // 약 110도
+
+pub fn load_loot_tables(mut loot_db: ResMut<LootTableDatabase>) {
+    match std::fs::read_to_string(LOOT_TABLE_PATH) {
+        Ok(json) => match serde_json::from_str::<HashMap<String, Vec<LootDrop>>>(&json) {
+            Ok(tables) => {
+                info!("Loaded {} loot tables from {}", tables.len(), LOOT_TABLE_PATH);
+                loot_db.tables.extend(tables);
+            }
+            Err(e) => error!("Failed to parse loot table JSON: {}", e),
+        },
+        Err(e) => warn!("Failed to read loot table file, enemies drop nothing: {}", e),
+    }
+}
+
+// 저장된 획득 목록 로드 - 파일이 없으면 새 게임
+pub fn load_collected_loot(mut collected: ResMut<CollectedLoot>) {
+    if let Some(saved) = load_save_file::<CollectedLoot>(COLLECTED_LOOT_SAVE_PATH, "collected loot") {
+        info!("Loaded {} collected loot entries", saved.ids.len());
+        *collected = saved;
+    }
+}
+
+// 빛나는 아이템 표식 생성 - 월드 배치 아이템과 적 전리품 공용
+pub fn spawn_item_pickup(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    position: Vec3,
+    item_id: &str,
+    quantity: u32,
+    loot_id: Option<String>,
+) {
+    commands.spawn((
+        Mesh3d(meshes.add(Sphere::new(0.15).mesh().ico(2).unwrap())),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: Color::srgb(1.0, 0.9, 0.6),
+            emissive: LinearRgba::new(2.0, 1.6, 0.8, 1.0),
+            ..default()
+        })),
+        Transform::from_translation(position + Vec3::Y * 0.3),
+        ItemPickup {
+            item_id: item_id.to_string(),
+            quantity,
+            loot_id,
+        },
+        Name::new(format!("Pickup_{}", item_id)),
+    )).with_children(|parent| {
+        parent.spawn((
+            PointLight {
+                color: Color::srgb(1.0, 0.85, 0.5),
+                intensity: 8000.0,
+                range: 3.0,
+                ..default()
+            },
+            Transform::from_xyz(0.0, 0.3, 0.0),
+        ));
+    });
+}
+
+// 이미 얻은 배치 아이템은 치우고, 이미 연 상자는 열린 채로 둠
+pub fn init_collected_loot(
+    mut commands: Commands,
+    collected: Res<CollectedLoot>,
+    pickup_query: Query<(Entity, &ItemPickup), Added<ItemPickup>>,
+    mut chest_query: Query<(&mut Chest, &Children), Added<Chest>>,
+    mut lid_query: Query<&mut ChestLid>,
+) {
+    for (entity, pickup) in pickup_query.iter() {
+        if pickup.loot_id.as_ref().is_some_and(|loot_id| collected.ids.contains(loot_id)) {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+
+    for (mut chest, children) in chest_query.iter_mut() {
+        if !collected.ids.contains(&chest.loot_id) {
+            continue;
+        }
+        chest.opened = true;
+        for &child in children.iter() {
+            if let Ok(mut lid) = lid_query.get_mut(child) {
+                lid.progress = 1.0;
+            }
+        }
+    }
+}
+
+// 적 처치 시 전리품 테이블에 따라 아이템 드롭
+pub fn loot_drop_system(
+    mut commands: Commands,
+    mut death_events: EventReader<DeathEvent>,
+    loot_db: Res<LootTableDatabase>,
+    enemy_query: Query<&Enemy>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let mut rng = rand::thread_rng();
+
+    for event in death_events.read() {
+        let Some(table_id) = enemy_query.get(event.entity).ok().and_then(|enemy| enemy.loot_table.as_ref()) else {
+            continue;
+        };
+        let Some(table) = loot_db.get(table_id) else {
+            warn!("Loot table '{}' not found", table_id);
+            continue;
+        };
+
+        for drop in table {
+            if rng.gen::<f32>() >= drop.chance {
+                continue;
+            }
+            let angle = rng.gen::<f32>() * std::f32::consts::TAU;
+            let scatter = Vec3::new(angle.cos(), 0.0, angle.sin()) * rng.gen_range(0.0..LOOT_SCATTER_RADIUS);
+            spawn_item_pickup(
+                &mut commands,
+                &mut meshes,
+                &mut materials,
+                event.position + scatter,
+                &drop.item_id,
+                drop.quantity,
+                None,
+            );
+            info!("Enemy dropped {} x{}", drop.item_id, drop.quantity);
+        }
+    }
+}
+
+// 가장 가까운 아이템 줍기 / 상자 열기
+pub fn loot_interaction_system(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    input_mapping: Res<InputMapping>,
+    mut collected: ResMut<CollectedLoot>,
+    mut acquired_events: EventWriter<ItemAcquiredEvent>,
+    player_query: Query<(&Transform, &Health), With<Player>>,
+    pickup_query: Query<(Entity, &GlobalTransform, &ItemPickup)>,
+    mut chest_query: Query<(&GlobalTransform, &mut Chest)>,
+) {
+    if !keyboard_input.just_pressed(input_mapping.interact) {
+        return;
+    }
+
+    let Ok((player_transform, health)) = player_query.get_single() else {
+        return;
+    };
+    if health.current <= 0.0 {
+        return;
+    }
+    let player_position = player_transform.translation;
+
+    let nearest_pickup = pickup_query
+        .iter()
+        .map(|(entity, transform, pickup)| (entity, pickup, transform.translation().distance(player_position)))
+        .filter(|(.., distance)| *distance <= PICKUP_INTERACT_RANGE)
+        .min_by(|a, b| a.2.total_cmp(&b.2));
+
+    if let Some((entity, pickup, _)) = nearest_pickup {
+        acquired_events.send(ItemAcquiredEvent {
+            item_id: pickup.item_id.clone(),
+            quantity: pickup.quantity,
+        });
+        if let Some(loot_id) = &pickup.loot_id {
+            collected.ids.insert(loot_id.clone());
+            write_save_file(COLLECTED_LOOT_SAVE_PATH, "collected loot", &*collected);
+        }
+        commands.entity(entity).despawn_recursive();
+        return;
+    }
+
+    let nearest_chest = chest_query
+        .iter_mut()
+        .filter(|(_, chest)| !chest.opened)
+        .map(|(transform, chest)| {
+            let distance = transform.translation().distance(player_position);
+            (chest, distance)
+        })
+        .filter(|(_, distance)| *distance <= CHEST_INTERACT_RANGE)
+        .min_by(|a, b| a.1.total_cmp(&b.1));
+
+    if let Some((mut chest, _)) = nearest_chest {
+        chest.opened = true;
+        for (item_id, quantity) in &chest.contents {
+            acquired_events.send(ItemAcquiredEvent {
+                item_id: item_id.clone(),
+                quantity: *quantity,
+            });
+        }
+        collected.ids.insert(chest.loot_id.clone());
+        write_save_file(COLLECTED_LOOT_SAVE_PATH, "collected loot", &*collected);
+        info!("Opened chest {}", chest.loot_id);
+    }
+}
+
+// 상호작용 키는 줍기 / 상자 열기가 우선 - 휴식, 상점 등은 이 범위 안에 주울 것이 있으면 양보
+pub fn is_loot_in_reach(
+    player_position: Vec3,
+    pickup_query: &Query<&GlobalTransform, With<ItemPickup>>,
+    chest_query: &Query<(&GlobalTransform, &Chest)>,
+) -> bool {
+    pickup_query
+        .iter()
+        .any(|transform| transform.translation().distance(player_position) <= PICKUP_INTERACT_RANGE)
+        || chest_query
+            .iter()
+            .any(|(transform, chest)| !chest.opened && transform.translation().distance(player_position) <= CHEST_INTERACT_RANGE)
+}
+
+// 얻은 아이템을 소지품에 추가 - 회복 물약 강화 아이템은 바로 사용
+pub fn item_acquired_system(
+    mut acquired_events: EventReader<ItemAcquiredEvent>,
+    mut upgrade_events: EventWriter<FlaskUpgradeEvent>,
+    item_db: Res<ItemDatabase>,
+    mut inventory_query: Query<&mut Inventory, With<Player>>,
+) {
+    let Ok(mut inventory) = inventory_query.get_single_mut() else {
+        return;
+    };
+
+    for event in acquired_events.read() {
+        match item_db.get(&event.item_id).map(|item| &item.kind) {
+            Some(ItemKind::FlaskUpgrade { upgrade }) => {
+                for _ in 0..event.quantity {
+                    upgrade_events.send(FlaskUpgradeEvent { upgrade: *upgrade });
+                }
+            }
+            Some(_) => inventory.add(&event.item_id, event.quantity),
+            None => warn!("Acquired unknown item '{}'", event.item_id),
+        }
+        info!("Acquired {} x{}", item_db.name(&event.item_id), event.quantity);
+    }
+}
+
+// 연 상자의 뚜껑을 경첩 기준으로 천천히 젖힘
+pub fn chest_lid_system(
+    time: Res<Time>,
+    chest_query: Query<(&Chest, &Children)>,
+    mut lid_query: Query<(&mut ChestLid, &mut Transform)>,
+) {
+    for (chest, children) in chest_query.iter() {
+        if !chest.opened {
+            continue;
+        }
+        for &child in children.iter() {
+            let Ok((mut lid, mut transform)) = lid_query.get_mut(child) else {
+                continue;
+            };
+            // 다 열린 뒤에는 건드리지 않음 (저장된 상자는 init_collected_loot 가 바로 1.0 으로 설정)
+            if lid.progress >= 1.0 && !lid.is_changed() {
+                continue;
+            }
+            lid.progress = (lid.progress + time.delta_secs() / CHEST_OPEN_DURATION).min(1.0);
+            // 끝에서 느려지는 ease-out
+            let eased = 1.0 - (1.0 - lid.progress).powi(2);
+            transform.rotation = Quat::from_rotation_x(-CHEST_LID_OPEN_ANGLE * eased);
+        }
+    }
+}
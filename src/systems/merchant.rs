@@ -0,0 +1,335 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use std::collections::HashMap;
+use crate::components::*;
+use crate::resources::*;
+use crate::utils::save::{load_save_file, write_save_file};
+use crate::states::GameState;
+use crate::systems::flask::apply_flask_upgrade;
+use crate::systems::loot::is_loot_in_reach;
+use crate::systems::third_person_camera::ThirdPersonCamera;
+use crate::systems::ui::{menu_root, set_menu_cursor, MENU_ENTRY_COLOR, MENU_SELECTED_COLOR};
+
+// 이 거리 안에서 상호작용 키를 누르면 상점 메뉴
+pub const MERCHANT_INTERACT_RANGE: f32 = 3.0;
+// 상점 메뉴에 한 번에 보이는 줄 수
+const SHOP_VISIBLE_ROWS: usize = 8;
+
+const MENU_UNAVAILABLE_COLOR: Color = Color::srgb(0.4, 0.4, 0.4);
+
+pub fn load_merchant_database(mut merchant_db: ResMut<MerchantDatabase>) {
+    match std::fs::read_to_string(MERCHANT_DATA_PATH) {
+        Ok(json) => match serde_json::from_str::<HashMap<String, MerchantDefinition>>(&json) {
+            Ok(merchants) => {
+                info!("Loaded {} merchants from {}", merchants.len(), MERCHANT_DATA_PATH);
+                merchant_db.merchants.extend(merchants);
+            }
+            Err(e) => error!("Failed to parse merchant JSON: {}", e),
+        },
+        Err(e) => warn!("Failed to read merchant file, shops are empty: {}", e),
+    }
+}
+
+// 저장된 한정 상품 구매 기록 로드 - 파일이 없으면 새 게임
+pub fn load_purchased_stock(mut purchased: ResMut<PurchasedStock>) {
+    if let Some(saved) = load_save_file::<PurchasedStock>(MERCHANT_STOCK_SAVE_PATH, "merchant stock") {
+        info!("Loaded purchase records for {} merchants", saved.merchants.len());
+        *purchased = saved;
+    }
+}
+
+// 무기 상태는 종류별로 공유되므로 이미 가진 무기는 다시 살 수 없음 (WeaponConditions 참고)
+fn is_owned_weapon(item_id: &str, inventory: &Inventory, item_db: &ItemDatabase) -> bool {
+    matches!(item_db.get(item_id).map(|item| &item.kind), Some(ItemKind::Weapon(_))) && inventory.count(item_id) > 0
+}
+
+// 팔 수 있는 소지품과 개수 - 장착 중인 한 개는 남겨 둠
+fn sellable_items(inventory: &Inventory, equipment: &Equipment, item_db: &ItemDatabase) -> Vec<(String, u32)> {
+    inventory
+        .items
+        .iter()
+        .filter(|item| item_db.get(&item.item_id).is_some_and(|def| def.sell_value > 0))
+        .map(|item| {
+            let equipped = equipment.equipped_ids().any(|id| *id == item.item_id);
+            (item.item_id.clone(), item.quantity - u32::from(equipped))
+        })
+        .filter(|(_, quantity)| *quantity > 0)
+        .collect()
+}
+
+// 상인 근처에서 상호작용 키 - 상점 메뉴 열기 (가까운 아이템 줍기가 우선)
+pub fn open_shop_menu(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    input_mapping: Res<InputMapping>,
+    merchant_db: Res<MerchantDatabase>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut shop_menu: ResMut<ShopMenuState>,
+    player_query: Query<(&Transform, &Health), With<Player>>,
+    merchant_query: Query<(&GlobalTransform, &Merchant)>,
+    pickup_query: Query<&GlobalTransform, With<ItemPickup>>,
+    chest_query: Query<(&GlobalTransform, &Chest)>,
+) {
+    if !keyboard_input.just_pressed(input_mapping.interact) {
+        return;
+    }
+
+    let Ok((player_transform, health)) = player_query.get_single() else {
+        return;
+    };
+    if health.current <= 0.0 {
+        return;
+    }
+    let player_position = player_transform.translation;
+
+    if is_loot_in_reach(player_position, &pickup_query, &chest_query) {
+        return;
+    }
+
+    let nearest = merchant_query
+        .iter()
+        .map(|(transform, merchant)| (merchant, transform.translation().distance(player_position)))
+        .filter(|(_, distance)| *distance <= MERCHANT_INTERACT_RANGE)
+        .min_by(|a, b| a.1.total_cmp(&b.1));
+
+    let Some((merchant, _)) = nearest else {
+        return;
+    };
+    if merchant_db.get(&merchant.merchant_id).is_none() {
+        warn!("Merchant '{}' has no stock data", merchant.merchant_id);
+        return;
+    }
+
+    shop_menu.merchant_id = merchant.merchant_id.clone();
+    shop_menu.tab = ShopTab::Buy;
+    shop_menu.selected = 0;
+    next_state.set(GameState::Shop);
+}
+
+pub fn setup_shop_menu(
+    mut commands: Commands,
+    mut window_q: Query<&mut Window, With<PrimaryWindow>>,
+    mut cam_q: Query<&mut ThirdPersonCamera>,
+) {
+    set_menu_cursor(&mut window_q, &mut cam_q, true);
+
+    commands.spawn((
+        menu_root(),
+        ShopMenu,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new("SHOP"),
+            TextFont {
+                font_size: 48.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+
+        // 상인 이름, 탭, 보유 소울 (매 프레임 갱신)
+        parent.spawn((
+            Text::new(""),
+            TextFont {
+                font_size: 22.0,
+                ..default()
+            },
+            TextColor(Color::srgb(1.0, 1.0, 0.0)),
+            Node {
+                margin: UiRect::bottom(Val::Px(12.0)),
+                ..default()
+            },
+            ShopSummary,
+        ));
+
+        for row in 0..SHOP_VISIBLE_ROWS {
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(MENU_ENTRY_COLOR),
+                ShopEntry(row),
+            ));
+        }
+
+        parent.spawn((
+            Text::new("W/S to select, A/D to switch BUY/SELL, ENTER to confirm, ESC to close"),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.6, 0.6, 0.6)),
+            Node {
+                margin: UiRect::top(Val::Px(24.0)),
+                ..default()
+            },
+        ));
+    });
+}
+
+// 상점 메뉴 조작 - 구매 탭은 소울로 상품 구입, 판매 탭은 소지품을 소울로 바꿈
+pub fn shop_menu_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    input_mapping: Res<InputMapping>,
+    item_db: Res<ItemDatabase>,
+    merchant_db: Res<MerchantDatabase>,
+    mut purchased: ResMut<PurchasedStock>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut shop_menu: ResMut<ShopMenuState>,
+    mut player_query: Query<(&mut Souls, &mut Inventory, &Equipment, &mut HealingFlask, &mut WeaponConditions), With<Player>>,
+    mut summary_query: Query<&mut Text, (With<ShopSummary>, Without<ShopEntry>)>,
+    mut entry_query: Query<(&ShopEntry, &mut Text, &mut TextColor), Without<ShopSummary>>,
+) {
+    if keyboard_input.just_pressed(input_mapping.pause) || keyboard_input.just_pressed(input_mapping.interact) {
+        next_state.set(GameState::Playing);
+        return;
+    }
+
+    let Some(merchant) = merchant_db.get(&shop_menu.merchant_id) else {
+        next_state.set(GameState::Playing);
+        return;
+    };
+    let Ok((mut souls, mut inventory, equipment, mut flask, mut conditions)) = player_query.get_single_mut() else {
+        return;
+    };
+
+    if keyboard_input.any_just_pressed([
+        input_mapping.move_left,
+        input_mapping.move_right,
+        KeyCode::ArrowLeft,
+        KeyCode::ArrowRight,
+    ]) {
+        shop_menu.tab = match shop_menu.tab {
+            ShopTab::Buy => ShopTab::Sell,
+            ShopTab::Sell => ShopTab::Buy,
+        };
+        shop_menu.selected = 0;
+    }
+
+    let mut sellable = sellable_items(&inventory, equipment, &item_db);
+    let count = match shop_menu.tab {
+        ShopTab::Buy => merchant.stock.len(),
+        ShopTab::Sell => sellable.len(),
+    };
+    if count > 0 {
+        if keyboard_input.any_just_pressed([input_mapping.move_forward, KeyCode::ArrowUp]) {
+            shop_menu.selected = (shop_menu.selected + count - 1) % count;
+        }
+        if keyboard_input.any_just_pressed([input_mapping.move_backward, KeyCode::ArrowDown]) {
+            shop_menu.selected = (shop_menu.selected + 1) % count;
+        }
+    }
+
+    if keyboard_input.just_pressed(KeyCode::Enter) && shop_menu.selected < count {
+        match shop_menu.tab {
+            ShopTab::Buy => {
+                let entry = &merchant.stock[shop_menu.selected];
+                let name = item_db.name(&entry.item_id);
+                let flask_upgrade = match item_db.get(&entry.item_id).map(|item| &item.kind) {
+                    Some(ItemKind::FlaskUpgrade { upgrade }) => Some(*upgrade),
+                    _ => None,
+                };
+                if purchased.remaining(&shop_menu.merchant_id, entry) == Some(0) {
+                    info!("{} is sold out", name);
+                } else if is_owned_weapon(&entry.item_id, &inventory, &item_db) {
+                    info!("Already own {}", name);
+                } else if souls.count < entry.price {
+                    info!("Not enough souls to buy {} ({} / {})", name, souls.count, entry.price);
+                } else if flask_upgrade.is_some_and(|upgrade| !flask.can_upgrade(upgrade)) {
+                    info!("Flask is already at its limit, {} not bought", name);
+                } else {
+                    souls.count -= entry.price;
+                    match flask_upgrade {
+                        Some(upgrade) => apply_flask_upgrade(&mut flask, upgrade),
+                        None => inventory.add(&entry.item_id, 1),
+                    }
+                    if entry.quantity.is_some() {
+                        purchased.record(&shop_menu.merchant_id, &entry.item_id);
+                        write_save_file(MERCHANT_STOCK_SAVE_PATH, "merchant stock", &*purchased);
+                    }
+                    info!("Bought {} for {} souls", name, entry.price);
+                }
+            }
+            ShopTab::Sell => {
+                let (item_id, _) = &sellable[shop_menu.selected];
+                let value = item_db.get(item_id).map_or(0, |item| item.sell_value);
+                if inventory.remove(item_id, 1) {
+                    souls.count += value;
+                    if inventory.count(item_id) == 0 {
+                        conditions.weapons.remove(item_id);
+                    }
+                    info!("Sold {} for {} souls", item_db.name(item_id), value);
+                }
+                // 다 팔린 아이템은 목록에서 빠지므로 선택 위치를 맞춤
+                sellable = sellable_items(&inventory, equipment, &item_db);
+                shop_menu.selected = shop_menu.selected.min(sellable.len().saturating_sub(1));
+            }
+        }
+    }
+
+    for mut text in summary_query.iter_mut() {
+        let tabs = match shop_menu.tab {
+            ShopTab::Buy => "[BUY]   SELL ",
+            ShopTab::Sell => " BUY   [SELL]",
+        };
+        text.0 = format!("{}    {}    Souls {}", merchant.name, tabs, souls.count);
+    }
+
+    // 선택한 줄이 항상 보이도록 목록을 스크롤
+    let first = (shop_menu.selected + 1).saturating_sub(SHOP_VISIBLE_ROWS);
+    for (entry, mut text, mut color) in entry_query.iter_mut() {
+        let index = first + entry.0;
+        let selected = shop_menu.selected == index;
+        let (label, available) = match shop_menu.tab {
+            ShopTab::Buy => match merchant.stock.get(index) {
+                Some(stock) => {
+                    let remaining = purchased.remaining(&shop_menu.merchant_id, stock);
+                    let stock_label = match remaining {
+                        Some(0) => "Sold out".to_string(),
+                        Some(remaining) => format!("x{}", remaining),
+                        None => String::new(),
+                    };
+                    let label = format!(
+                        "{:<22} {:>6}  {:<8}  Owned {}",
+                        item_db.name(&stock.item_id),
+                        stock.price,
+                        stock_label,
+                        inventory.count(&stock.item_id),
+                    );
+                    let owned_weapon = is_owned_weapon(&stock.item_id, &inventory, &item_db);
+                    (label, remaining != Some(0) && !owned_weapon && souls.count >= stock.price)
+                }
+                None => (String::new(), true),
+            },
+            ShopTab::Sell => match sellable.get(index) {
+                Some((item_id, quantity)) => {
+                    let value = item_db.get(item_id).map_or(0, |item| item.sell_value);
+                    (format!("{:<22} {:>6}  x{}", item_db.name(item_id), value, quantity), true)
+                }
+                None if index == 0 => ("Nothing to sell".to_string(), false),
+                None => (String::new(), true),
+            },
+        };
+        text.0 = label;
+        color.0 = if selected {
+            MENU_SELECTED_COLOR
+        } else if available {
+            MENU_ENTRY_COLOR
+        } else {
+            MENU_UNAVAILABLE_COLOR
+        };
+    }
+}
+
+pub fn cleanup_shop_menu(
+    mut commands: Commands,
+    menu_query: Query<Entity, With<ShopMenu>>,
+    mut window_q: Query<&mut Window, With<PrimaryWindow>>,
+    mut cam_q: Query<&mut ThirdPersonCamera>,
+) {
+    set_menu_cursor(&mut window_q, &mut cam_q, false);
+
+    for entity in menu_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
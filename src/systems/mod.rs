@@ -2,7 +2,24 @@ pub mod player_movement;
 pub mod camera;
 pub mod input;
 pub mod combat;
+pub mod damage;
+pub mod lock_on;
+pub mod souls;
+pub mod flask;
+pub mod leveling;
+pub mod equipment;
+pub mod smithing;
+pub mod loot;
+pub mod progress;
+pub mod merchant;
+pub mod checkpoint;
+pub mod fast_travel;
+pub mod enemy_spawn;
 pub mod enemy_ai;
+pub mod perception;
+pub mod noise;
+pub mod projectile;
+pub mod boss;
 pub mod ui;
 pub mod model_loader;
 pub mod animation;
@@ -22,7 +39,24 @@ pub use player_movement::*;
 pub use camera::*;
 pub use input::*;
 pub use combat::*;
+pub use damage::*;
+pub use lock_on::*;
+pub use souls::*;
+pub use flask::*;
+pub use leveling::*;
+pub use equipment::*;
+pub use smithing::*;
+pub use loot::*;
+pub use progress::*;
+pub use merchant::*;
+pub use checkpoint::*;
+pub use fast_travel::*;
+pub use enemy_spawn::*;
 pub use enemy_ai::*;
+pub use perception::*;
+pub use noise::*;
+pub use projectile::*;
+pub use boss::*;
 pub use ui::*;
 pub use model_loader::*;
 pub use animation::*;
@@ -0,0 +1,208 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use rand::prelude::*;
+use crate::components::*;
+use crate::resources::*;
+
+// 발소리 간격과 소음 반경 - 천천히 걷기 (가드 중 등) / 걷기 / 달리기 / 구르기
+const FOOTSTEP_INTERVAL: f32 = 0.45;
+const SNEAK_NOISE_RADIUS: f32 = 1.0;
+const WALK_NOISE_RADIUS: f32 = 4.0;
+const SPRINT_NOISE_RADIUS: f32 = 12.0;
+const ROLL_NOISE_RADIUS: f32 = 8.0;
+// 무기가 맞거나 막히는 소리
+const COMBAT_NOISE_RADIUS: f32 = 15.0;
+// 벽 / 지형에 가로막힌 소리는 이 배율의 거리까지만 들림
+const OCCLUDED_NOISE_SCALE: f32 = 0.5;
+// 소리가 난 곳을 살피는 시간
+const NOISE_SEARCH_TIME: f32 = 6.0;
+
+pub fn load_sound_traps(mut trap_db: ResMut<SoundTrapDatabase>) {
+    match std::fs::read_to_string(SOUND_TRAP_DATA_PATH) {
+        Ok(json) => match serde_json::from_str::<Vec<SoundTrapArea>>(&json) {
+            Ok(areas) => {
+                info!("Loaded {} sound trap areas from {}", areas.len(), SOUND_TRAP_DATA_PATH);
+                trap_db.areas = areas;
+            }
+            Err(e) => error!("Failed to parse sound trap JSON: {}", e),
+        },
+        Err(e) => warn!("Failed to read sound trap file, no sound traps placed: {}", e),
+    }
+}
+
+// 맵 데이터의 함정 구역마다 판정 영역과 바닥 수정 파편 배치
+pub fn spawn_sound_traps(
+    mut commands: Commands,
+    trap_db: Res<SoundTrapDatabase>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let mut rng = rand::thread_rng();
+    let shard_mesh = meshes.add(Cone { radius: 0.12, height: 0.35 });
+    let shard_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.6, 0.85, 1.0),
+        emissive: LinearRgba::new(0.3, 0.5, 0.9, 1.0),
+        perceptual_roughness: 0.15,
+        ..default()
+    });
+
+    for area in &trap_db.areas {
+        let min = Vec3::from(area.min);
+        let max = Vec3::from(area.max);
+        let size = max - min;
+
+        commands.spawn((
+            SoundTrap {
+                min,
+                max,
+                sound_radius: area.sound_radius,
+            },
+            Transform::from_translation((min + max) / 2.0),
+            Visibility::default(),
+            Name::new(format!("SoundTrap_{}", area.id)),
+        )).with_children(|parent| {
+            let shard_count = (size.x * size.z * area.ground_cover_density) as usize;
+            for _ in 0..shard_count {
+                let position = Vec3::new(
+                    rng.gen_range(-0.5..0.5) * size.x,
+                    -size.y / 2.0 + 0.1,
+                    rng.gen_range(-0.5..0.5) * size.z,
+                );
+                let tilt = Quat::from_rotation_x(rng.gen_range(-0.4..0.4)) * Quat::from_rotation_z(rng.gen_range(-0.4..0.4));
+                parent.spawn((
+                    Mesh3d(shard_mesh.clone()),
+                    MeshMaterial3d(shard_material.clone()),
+                    Transform::from_translation(position)
+                        .with_rotation(tilt)
+                        .with_scale(Vec3::splat(rng.gen_range(0.5..1.0))),
+                ));
+            }
+        });
+        info!("Placed sound trap area {}", area.id);
+    }
+}
+
+// 플레이어 발소리 / 구르기 소음 - 소음 함정 위에서는 함정 반경만큼 크게 울림
+pub fn player_noise_system(
+    time: Res<Time>,
+    mut noise_events: EventWriter<NoiseEvent>,
+    mut footstep_timer: Local<f32>,
+    mut was_rolling: Local<bool>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    input_mapping: Res<InputMapping>,
+    player_query: Query<(Entity, &Transform, &Player, &Velocity, &Health, &Stamina, &GuardState, &HealingFlask)>,
+    trap_query: Query<&SoundTrap>,
+) {
+    let Ok((player_entity, transform, player, velocity, health, stamina, guard_state, flask)) = player_query.get_single() else {
+        return;
+    };
+    if health.current <= 0.0 {
+        return;
+    }
+
+    let position = transform.translation;
+    let trap_radius = trap_query
+        .iter()
+        .filter(|trap| trap.contains(position))
+        .map(|trap| trap.sound_radius)
+        .reduce(f32::max);
+
+    let started_rolling = player.is_rolling && !*was_rolling;
+    *was_rolling = player.is_rolling;
+    if started_rolling {
+        noise_events.send(NoiseEvent {
+            source: player_entity,
+            position,
+            radius: trap_radius.unwrap_or(0.0).max(ROLL_NOISE_RADIUS),
+        });
+        *footstep_timer = FOOTSTEP_INTERVAL;
+        return;
+    }
+
+    let speed = Vec3::new(velocity.linvel.x, 0.0, velocity.linvel.z).length();
+    if player.is_rolling || speed < 0.5 {
+        *footstep_timer = 0.0;
+        return;
+    }
+
+    *footstep_timer -= time.delta_secs();
+    if *footstep_timer > 0.0 {
+        return;
+    }
+    *footstep_timer = FOOTSTEP_INTERVAL;
+
+    // 발소리 종류는 이동 입력으로 판단 (player_movement_system 과 같은 조건) - 상태이상으로 느려져도 걷는 소리
+    let sneaking = guard_state.is_guarding || flask.is_drinking();
+    let sprinting = !sneaking && keyboard_input.pressed(input_mapping.sprint) && stamina.current > 0.0;
+    let footstep_radius = if sprinting {
+        SPRINT_NOISE_RADIUS
+    } else if sneaking {
+        SNEAK_NOISE_RADIUS
+    } else {
+        WALK_NOISE_RADIUS
+    };
+    noise_events.send(NoiseEvent {
+        source: player_entity,
+        position,
+        radius: trap_radius.unwrap_or(0.0).max(footstep_radius),
+    });
+}
+
+// 공격이 맞거나 막힌 소리 - 상태이상 지속 피해와 조용한 뒤잡기/앞잡기는 소리 없음
+pub fn combat_noise_system(
+    mut damage_events: EventReader<DamageEvent>,
+    mut noise_events: EventWriter<NoiseEvent>,
+) {
+    for event in damage_events.read() {
+        if matches!(event.source, DamageSource::StatusEffect | DamageSource::Critical) {
+            continue;
+        }
+        noise_events.send(NoiseEvent {
+            source: event.attacker,
+            position: event.hit_position,
+            radius: COMBAT_NOISE_RADIUS,
+        });
+    }
+}
+
+// 소리를 들은 적은 아직 플레이어를 발견하지 못했다면 소리가 난 곳으로 살피러 감
+// 소리가 난 곳과 적 사이가 벽 / 지형에 가로막혀 있으면 들리는 거리가 줄어듦
+pub fn hearing_system(
+    rapier_context: ReadDefaultRapierContext,
+    mut noise_events: EventReader<NoiseEvent>,
+    mut enemy_query: Query<(Entity, &Transform, &mut AIState), (With<Perception>, Without<Dead>)>,
+) {
+    let rapier_context = rapier_context.single();
+
+    for event in noise_events.read() {
+        for (enemy_entity, transform, mut ai_state) in enemy_query.iter_mut() {
+            if enemy_entity == event.source {
+                continue;
+            }
+            if !matches!(
+                ai_state.current_state,
+                AIStateType::Idle | AIStateType::Patrol | AIStateType::SearchLastKnown
+            ) {
+                continue;
+            }
+            let to_enemy = transform.translation - event.position;
+            let distance = to_enemy.length();
+            if distance > event.radius {
+                continue;
+            }
+            if distance > OCCLUDED_NOISE_SCALE * event.radius {
+                let filter = QueryFilter::only_fixed().exclude_sensors();
+                let occluded = rapier_context
+                    .cast_ray(event.position, to_enemy / distance, distance, true, filter)
+                    .is_some();
+                if occluded {
+                    continue;
+                }
+            }
+
+            ai_state.current_state = AIStateType::SearchLastKnown;
+            ai_state.state_timer = NOISE_SEARCH_TIME;
+            ai_state.last_known_player_position = event.position;
+        }
+    }
+}
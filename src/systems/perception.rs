@@ -0,0 +1,128 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use crate::components::*;
+use crate::utils::math::{angle_between_vectors, facing_direction};
+
+// 시선 높이 - 적은 캡슐 중심 기준 눈 위치에서, 플레이어는 가슴 높이를 봄
+const ENEMY_EYE_HEIGHT: f32 = 0.8;
+const PLAYER_CHEST_HEIGHT: f32 = 0.5;
+// 시야 끝에서는 가까이 있을 때보다 이 비율만큼 느리게 알아챔
+const MIN_DISTANCE_GAIN_SCALE: f32 = 0.3;
+// 이미 발견한 뒤에는 시야각과 상관없이 이 배율의 거리까지 계속 추적
+const ALERTED_SIGHT_RANGE_SCALE: f32 = 1.5;
+// 경계도 표시 - 경계도가 막 오르기 시작했을 때의 크기 배율과 색 (의심 / 발견)
+const INDICATOR_MIN_SCALE: f32 = 0.3;
+const INDICATOR_SUSPICIOUS_COLOR: Color = Color::srgb(1.0, 0.85, 0.2);
+const INDICATOR_ALERTED_COLOR: Color = Color::srgb(1.0, 0.15, 0.1);
+
+// 적 시야 판정 - 시야각 안에 있고 지형/벽에 가려지지 않으면 경계도 증가
+pub fn perception_system(
+    time: Res<Time>,
+    rapier_context: ReadDefaultRapierContext,
+    mut damage_events: EventReader<DamageEvent>,
+    player_query: Query<(Entity, &Transform, &Health), With<Player>>,
+    mut enemy_query: Query<(Entity, &Transform, &mut Perception), (With<Enemy>, Without<Player>, Without<Dead>)>,
+) {
+    let rapier_context = rapier_context.single();
+    let Ok((player_entity, player_transform, player_health)) = player_query.get_single() else {
+        return;
+    };
+
+    // 플레이어에게 맞으면 보지 못했더라도 바로 알아챔 (뒤잡기 포함)
+    for event in damage_events.read() {
+        if event.attacker != player_entity {
+            continue;
+        }
+        if let Ok((.., mut perception)) = enemy_query.get_mut(event.victim) {
+            perception.alert();
+        }
+    }
+
+    let delta = time.delta_secs();
+    let target = player_transform.translation + Vec3::Y * PLAYER_CHEST_HEIGHT;
+
+    for (enemy_entity, enemy_transform, mut perception) in enemy_query.iter_mut() {
+        let eye = enemy_transform.translation + Vec3::Y * ENEMY_EYE_HEIGHT;
+        let to_player = target - eye;
+        let distance = to_player.length();
+
+        let sight_range = if perception.is_alerted() {
+            perception.sight_range * ALERTED_SIGHT_RANGE_SCALE
+        } else {
+            perception.sight_range
+        };
+        let angle = angle_between_vectors(
+            facing_direction(enemy_transform),
+            Vec3::new(to_player.x, 0.0, to_player.z),
+        );
+
+        // 정면 / 주변 시야 구분 - 발견한 뒤에는 고개를 돌려 계속 지켜봄
+        let gain = if player_health.current <= 0.0 || distance > sight_range {
+            None
+        } else if perception.is_alerted() || angle <= perception.focus_angle {
+            Some(perception.focus_gain)
+        } else if angle <= perception.peripheral_angle {
+            Some(perception.peripheral_gain)
+        } else {
+            None
+        };
+
+        // 시야 안이라도 벽, 기둥, 폐허 등에 가려지면 보이지 않음
+        let visible = gain.is_some() && distance > f32::EPSILON && {
+            let filter = QueryFilter::default()
+                .exclude_sensors()
+                .exclude_rigid_body(enemy_entity);
+            match rapier_context.cast_ray(eye, to_player / distance, distance, true, filter) {
+                Some((hit_entity, _)) => hit_entity == player_entity,
+                None => true,
+            }
+        };
+
+        perception.sees_player = visible;
+        match gain {
+            Some(gain) if visible => {
+                let distance_scale = 1.0 - (1.0 - MIN_DISTANCE_GAIN_SCALE) * (distance / sight_range).min(1.0);
+                perception.awareness = (perception.awareness + gain * distance_scale * delta).min(1.0);
+                perception.time_since_seen = 0.0;
+            }
+            _ => {
+                perception.awareness = (perception.awareness - perception.decay_rate * delta).max(0.0);
+                perception.time_since_seen += delta;
+            }
+        }
+
+        // 발견 상태는 경계도가 다 찼을 때 켜지고 완전히 식었을 때만 꺼짐
+        if perception.awareness >= 1.0 {
+            perception.alerted = true;
+        } else if perception.awareness <= 0.0 {
+            perception.alerted = false;
+        }
+    }
+}
+
+// 머리 위 경계도 표시 갱신 - 경계도가 없으면 숨김
+pub fn awareness_indicator_system(
+    enemy_query: Query<(&Perception, &Children, Has<Dead>)>,
+    mut indicator_query: Query<(&mut Transform, &mut Visibility, &MeshMaterial3d<StandardMaterial>), With<AwarenessIndicator>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for (perception, children, dead) in enemy_query.iter() {
+        for &child in children.iter() {
+            let Ok((mut transform, mut visibility, material)) = indicator_query.get_mut(child) else {
+                continue;
+            };
+
+            let shown = !dead && (perception.awareness > 0.0 || perception.is_alerted());
+            *visibility = if shown { Visibility::Inherited } else { Visibility::Hidden };
+            transform.scale = Vec3::splat(INDICATOR_MIN_SCALE + (1.0 - INDICATOR_MIN_SCALE) * perception.awareness);
+
+            let color = if perception.is_alerted() { INDICATOR_ALERTED_COLOR } else { INDICATOR_SUSPICIOUS_COLOR };
+            // 색이 바뀔 때만 에셋 수정
+            if materials.get(&material.0).is_some_and(|m| m.base_color != color) {
+                if let Some(m) = materials.get_mut(&material.0) {
+                    m.base_color = color;
+                }
+            }
+        }
+    }
+}
@@ -2,20 +2,46 @@ use bevy::prelude::*;
 use bevy_rapier3d::prelude::*;
 use crate::components::*;
 use crate::resources::*;
+use crate::utils::math::facing_direction;
+
+// 백스텝은 짧고 느린 회피 (기본 구르기 대비 배율)
+const BACKSTEP_DURATION_SCALE: f32 = 0.6;
+const BACKSTEP_SPEED_SCALE: f32 = 0.7;
+// 물약 마시는 동안 이동 속도 배율
+const DRINK_SPEED_SCALE: f32 = 0.3;
 
 pub fn player_movement(
     time: Res<Time>,
     keyboard_input: Res<ButtonInput<KeyCode>>,
     input_mapping: Res<InputMapping>,
-    mut player_query: Query<(&mut Transform, &mut Velocity, &mut Player, &mut Stamina), With<Player>>,
+    mut player_query: Query<(&mut Transform, &mut Velocity, &mut Player, &mut Stamina, &Poise, &StatusBuildup, &GuardState, &CriticalAttackState, &LockOnState, &HealingFlask), With<Player>>,
     camera_query: Query<&Transform, (With<Camera3d>, Without<Player>)>,
+    target_query: Query<&Transform, (With<Enemy>, Without<Player>)>,
 ) {
     let Ok(camera_transform) = camera_query.get_single() else {
         return;
     };
     
-    for (mut transform, mut velocity, mut player, mut stamina) in player_query.iter_mut() {
+    for (mut transform, mut velocity, mut player, mut stamina, poise, status, guard_state, critical_state, lock_on, flask) in player_query.iter_mut() {
         let mut direction = Vec3::ZERO;
+
+        // 락온 중이면 대상을 향한 수평 방향
+        let lock_on_dir = lock_on
+            .target
+            .and_then(|target| target_query.get(target).ok())
+            .map(|target_transform| {
+                let to_target = target_transform.translation - transform.translation;
+                Vec3::new(to_target.x, 0.0, to_target.z).normalize_or_zero()
+            })
+            .filter(|dir| *dir != Vec3::ZERO);
+
+        // 강인도 경직 중에는 구르기도 끊기고 이동 불가
+        if poise.is_staggered() {
+            player.is_rolling = false;
+            velocity.linvel.x = 0.0;
+            velocity.linvel.z = 0.0;
+            continue;
+        }
         
         // 롤링 중이면 이동 제한
         if player.is_rolling {
@@ -26,8 +52,16 @@ pub fn player_movement(
             continue;
         }
 
-        // 스테미나 회복
-        stamina.current = (stamina.current + stamina.regen_rate * time.delta_secs()).min(stamina.max);
+        // 스테미나 회복 (가드 중에는 느리게)
+        let regen_scale = if guard_state.is_guarding { 0.3 } else { 1.0 };
+        stamina.current = (stamina.current + stamina.regen_rate * regen_scale * time.delta_secs()).min(stamina.max);
+
+        // 가드 브레이크 경직 / 치명타 연출 중에는 이동 불가
+        if guard_state.is_guard_broken() || critical_state.is_active() {
+            velocity.linvel.x = 0.0;
+            velocity.linvel.z = 0.0;
+            continue;
+        }
 
         // 카메라 기준으로 이동 방향 계산
         let camera_forward = camera_transform.forward().normalize();
@@ -51,20 +85,30 @@ pub fn player_movement(
             direction += right;
         }
 
-        // 롤링 처리 - 스테미나 필요
-        if keyboard_input.just_pressed(input_mapping.roll) && !player.is_rolling && stamina.current >= 20.0 {
-            player.is_rolling = true;
-            player.roll_timer = player.roll_duration;
-            stamina.current -= 20.0; // 롤링 스테미나 소모
+        // 롤링 처리 - 스테미나 필요 (물약 마시는 중에는 불가)
+        if keyboard_input.just_pressed(input_mapping.roll) && !player.is_rolling && !flask.is_drinking() && stamina.current >= player.roll_stamina_cost {
+            let (mut roll_duration, mut roll_speed, _) = player.roll_profile();
+            let facing = facing_direction(&transform);
             
-            // 롤링 방향 설정 (이동 방향이 없으면 앞쪽으로)
-            let roll_direction = if direction.length() > 0.1 {
-                direction.normalize()
+            // 롤링 방향 설정 (캐릭터 정면 기준, 이동 입력이 없으면 백스텝)
+            let roll_vector = if direction.length() > 0.1 {
+                let roll_vector = direction.normalize();
+                player.roll_direction = RollDirection::from_vectors(facing, roll_vector);
+                roll_vector
             } else {
-                *transform.forward()
+                player.roll_direction = RollDirection::Backstep;
+                roll_duration *= BACKSTEP_DURATION_SCALE;
+                roll_speed *= BACKSTEP_SPEED_SCALE;
+                -facing
             };
             
-            velocity.linvel = roll_direction * player.speed * 2.0;
+            player.is_rolling = true;
+            player.roll_timer = roll_duration;
+            player.current_roll_duration = roll_duration;
+            stamina.current -= player.roll_stamina_cost; // 롤링 스테미나 소모
+            
+            velocity.linvel.x = roll_vector.x * roll_speed;
+            velocity.linvel.z = roll_vector.z * roll_speed;
             continue;
         }
 
@@ -72,9 +116,13 @@ pub fn player_movement(
         if direction.length() > 0.1 {
             direction = direction.normalize();
             
-            // 달리기 확인 (Shift 키)
-            let is_running = keyboard_input.pressed(KeyCode::ShiftLeft);
-            let current_speed = if is_running && stamina.current > 0.0 {
+            // 달리기 확인 (가드 중에는 천천히 걷기만 가능, 물약 마시는 중에는 더 느리게)
+            let is_running = keyboard_input.pressed(input_mapping.sprint) && !guard_state.is_guarding && !flask.is_drinking();
+            let current_speed = if flask.is_drinking() {
+                player.speed * DRINK_SPEED_SCALE
+            } else if guard_state.is_guarding {
+                player.speed * 0.6
+            } else if is_running && stamina.current > 0.0 {
                 // 달리기 중 스테미나 소모
                 stamina.current = (stamina.current - 10.0 * time.delta_secs()).max(0.0);
                 player.speed * 1.5  // 달리기 속도 (1.5배)
@@ -82,20 +130,44 @@ pub fn player_movement(
                 player.speed
             };
             
+            // 중력 역병에 걸리면 몸이 무거워짐
+            let current_speed = if status.is_active(StatusType::GravityBlight) {
+                current_speed * 0.7
+            } else {
+                current_speed
+            };
+            
             velocity.linvel.x = direction.x * current_speed;
             velocity.linvel.z = direction.z * current_speed;
             
-            // 캐릭터를 이동 방향으로 회전
-            let target_rotation = Quat::from_rotation_arc(Vec3::Z, direction);
+            // 락온 중에는 대상을 바라본 채 옆걸음 (달리기는 이동 방향으로 회전)
+            let facing_dir = match lock_on_dir {
+                Some(dir) if !is_running => dir,
+                _ => direction,
+            };
+            let target_rotation = Quat::from_rotation_arc(Vec3::Z, facing_dir);
             transform.rotation = transform.rotation.slerp(target_rotation, 10.0 * time.delta_secs());
         } else {
             velocity.linvel.x = 0.0;
             velocity.linvel.z = 0.0;
             
-            // 이동하지 않을 때는 카메라 방향을 바라봄
-            let camera_dir = Vec3::new(camera_forward.x, 0.0, camera_forward.z).normalize();
+            // 이동하지 않을 때는 락온 대상 또는 카메라 방향을 바라봄
+            let camera_dir = lock_on_dir.unwrap_or_else(|| Vec3::new(camera_forward.x, 0.0, camera_forward.z).normalize());
             let target_rotation = Quat::from_rotation_arc(Vec3::Z, camera_dir);
             transform.rotation = transform.rotation.slerp(target_rotation, 5.0 * time.delta_secs());
         }
     }
+}
+
+// 장비 중량이 바뀌면 구르기 종류 갱신
+pub fn update_roll_type(
+    mut player_query: Query<(&mut Player, &EquipLoad), Changed<EquipLoad>>,
+) {
+    for (mut player, equip_load) in player_query.iter_mut() {
+        let roll_type = RollType::from_equip_load(equip_load.ratio());
+        if player.roll_type != roll_type {
+            info!("Roll type changed: {:?} -> {:?} (load {:.0}%)", player.roll_type, roll_type, equip_load.ratio() * 100.0);
+            player.roll_type = roll_type;
+        }
+    }
 }
\ No newline at end of file
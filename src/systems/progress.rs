@@ -0,0 +1,84 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::resources::*;
+use crate::utils::save::{load_save_file, write_save_file};
+
+pub fn load_player_progress(mut loaded: ResMut<LoadedPlayerProgress>) {
+    if let Some(progress) = load_save_file::<PlayerProgress>(PLAYER_PROGRESS_SAVE_PATH, "player progress") {
+        info!("Loaded player progress ({} souls, {} item stacks)", progress.souls, progress.inventory.len());
+        loaded.0 = Some(progress);
+    }
+}
+
+// 플레이어가 생성되면 시작 상태를 저장된 진행 상황으로 덮어씀
+pub fn apply_player_progress(
+    mut loaded: ResMut<LoadedPlayerProgress>,
+    mut player_query: Query<
+        (&mut Souls, &mut CharacterStats, &mut Inventory, &mut Equipment, &mut WeaponConditions, &mut HealingFlask),
+        Added<Player>,
+    >,
+) {
+    let Ok((mut souls, mut stats, mut inventory, mut equipment, mut conditions, mut flask)) = player_query.get_single_mut() else {
+        return;
+    };
+    let Some(progress) = loaded.0.take() else {
+        return;
+    };
+
+    souls.count = progress.souls;
+    // 체력 / 스테미나 최대치는 apply_character_stats 가 다시 계산
+    *stats = progress.stats;
+    inventory.items = progress
+        .inventory
+        .into_iter()
+        .map(|(item_id, quantity)| InventoryItem { item_id, quantity })
+        .collect();
+    *equipment = progress.equipment;
+    conditions.weapons = progress.weapon_conditions;
+    flask.max_charges = progress.flask_max_charges;
+    flask.potency_level = progress.flask_potency_level;
+    flask.refill();
+    info!("Restored player progress");
+}
+
+// 저장 대상이 바뀌면 기록 - 물약을 마시는 등 저장하지 않는 값만 바뀐 경우는 건너뜀
+pub fn save_player_progress(
+    mut last_saved: Local<Option<PlayerProgress>>,
+    player_query: Query<
+        (&Souls, &CharacterStats, &Inventory, &Equipment, &WeaponConditions, &HealingFlask),
+        (
+            With<Player>,
+            Or<(
+                Changed<Souls>,
+                Changed<CharacterStats>,
+                Changed<Inventory>,
+                Changed<Equipment>,
+                Changed<WeaponConditions>,
+                Changed<HealingFlask>,
+            )>,
+        ),
+    >,
+) {
+    let Ok((souls, stats, inventory, equipment, conditions, flask)) = player_query.get_single() else {
+        return;
+    };
+
+    let progress = PlayerProgress {
+        souls: souls.count,
+        stats: stats.clone(),
+        inventory: inventory
+            .items
+            .iter()
+            .map(|item| (item.item_id.clone(), item.quantity))
+            .collect(),
+        equipment: equipment.clone(),
+        weapon_conditions: conditions.weapons.clone(),
+        flask_max_charges: flask.max_charges,
+        flask_potency_level: flask.potency_level,
+    };
+    if last_saved.as_ref() == Some(&progress) {
+        return;
+    }
+    write_save_file(PLAYER_PROGRESS_SAVE_PATH, "player progress", &progress);
+    *last_saved = Some(progress);
+}
@@ -0,0 +1,127 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use crate::components::*;
+
+// Rapier 기본 중력 - 조준 보정 계산에 사용
+pub const GRAVITY: f32 = 9.81;
+// 지형에 박힌 화살이 사라지기까지의 시간
+const STUCK_PROJECTILE_LIFETIME: f32 = 10.0;
+const ARROW_LENGTH: f32 = 0.8;
+const ARROW_RADIUS: f32 = 0.03;
+
+// 화살 모양 투사체 생성 - 물리 이동은 Rapier, 명중 판정은 projectile_system
+pub fn spawn_projectile(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    projectile: Projectile,
+    origin: Vec3,
+    velocity: Vec3,
+) -> Entity {
+    let gravity_scale = projectile.gravity_scale;
+    commands.spawn((
+        Mesh3d(meshes.add(Cylinder::new(ARROW_RADIUS, ARROW_LENGTH))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: Color::srgb(0.45, 0.32, 0.2),
+            ..default()
+        })),
+        Transform::from_translation(origin).with_rotation(arrow_rotation(velocity)),
+        Projectile {
+            previous_position: origin,
+            ..projectile
+        },
+        RigidBody::Dynamic,
+        // 센서라서 부딪혀 튕기지 않음 - 명중은 이동 구간 레이캐스트로 판정
+        Collider::ball(ARROW_RADIUS * 2.0),
+        Sensor,
+        GravityScale(gravity_scale),
+        Velocity::linear(velocity),
+        LockedAxes::ROTATION_LOCKED,
+        Name::new("Projectile"),
+    )).id()
+}
+
+// 원기둥 메시는 Y축 방향이므로 진행 방향으로 눕힘
+fn arrow_rotation(velocity: Vec3) -> Quat {
+    if velocity.length_squared() < f32::EPSILON {
+        return Quat::IDENTITY;
+    }
+    Quat::from_rotation_arc(Vec3::Y, velocity.normalize())
+}
+
+// 투사체 비행 / 명중 - 캐릭터에 맞으면 피해 후 제거, 지형에 맞으면 박히거나 제거
+pub fn projectile_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut damage_events: EventWriter<DamageEvent>,
+    rapier_context: ReadDefaultRapierContext,
+    mut projectile_query: Query<(Entity, &mut Transform, &mut Projectile, Option<&Velocity>)>,
+    target_query: Query<&Health, Without<Dead>>,
+    team_query: Query<(Has<Player>, Has<Enemy>)>,
+) {
+    let rapier_context = rapier_context.single();
+
+    for (entity, mut transform, mut projectile, velocity) in projectile_query.iter_mut() {
+        projectile.lifetime -= time.delta_secs();
+        if projectile.lifetime <= 0.0 {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        if projectile.stuck {
+            continue;
+        }
+
+        let start = projectile.previous_position;
+        let travel = transform.translation - start;
+        projectile.previous_position = transform.translation;
+        if let Some(velocity) = velocity {
+            transform.rotation = arrow_rotation(velocity.linvel);
+        }
+
+        let distance = travel.length();
+        if distance < f32::EPSILON {
+            continue;
+        }
+
+        // 쏜 캐릭터, 같은 편 캐릭터, 다른 투사체, 공격 히트박스는 무시
+        // 적의 화살은 플레이어만, 플레이어의 투사체는 적만 맞히고 같은 편은 뚫고 지나감
+        let owner = projectile.owner;
+        let owner_team = team_query.get(owner).ok();
+        let is_ally = |other: Entity| match (owner_team, team_query.get(other)) {
+            (Some((owner_player, owner_enemy)), Ok((other_player, other_enemy))) => {
+                (owner_player && other_player) || (owner_enemy && other_enemy)
+            }
+            _ => false,
+        };
+        let predicate = |other: Entity| other != owner && !is_ally(other);
+        let filter = QueryFilter::default()
+            .exclude_sensors()
+            .exclude_rigid_body(owner)
+            .predicate(&predicate);
+        let Some((hit_entity, toi)) = rapier_context.cast_ray(start, travel / distance, distance, true, filter) else {
+            continue;
+        };
+        let hit_position = start + travel / distance * toi;
+
+        if target_query.contains(hit_entity) {
+            // 가드 / 구르기 무적 판정은 damage_resolution_system 에서 처리
+            damage_events.send(DamageEvent {
+                attacker: projectile.owner,
+                victim: hit_entity,
+                damage: projectile.damage.clone(),
+                poise_damage: projectile.poise_damage,
+                hit_position,
+                source: DamageSource::Projectile,
+            });
+            commands.entity(entity).despawn_recursive();
+        } else if projectile.stick_on_impact {
+            // 화살 끝이 표면에 걸치도록 살짝 뒤로 물려서 고정
+            projectile.stuck = true;
+            projectile.lifetime = STUCK_PROJECTILE_LIFETIME;
+            transform.translation = hit_position - travel / distance * (ARROW_LENGTH * 0.3);
+            commands.entity(entity).remove::<(RigidBody, Collider, Sensor, Velocity, GravityScale)>();
+        } else {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
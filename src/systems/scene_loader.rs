@@ -1,5 +1,14 @@
+use bevy::gltf::GltfExtras;
 use bevy::prelude::*;
+use std::collections::HashMap;
 use crate::{MyAssets, CheckpointMarker};
+use crate::components::{Enemy, EnemySpawnDefinition, PatrolMode, PatrolPath};
+use crate::systems::world_generation::WorldZone;
+
+// 커스텀 프로퍼티가 없는 씬 적의 기본값
+const SCENE_ENEMY_DEFAULT_HEALTH: f32 = 50.0;
+const SCENE_ENEMY_DEFAULT_POISE: f32 = 20.0;
+const SCENE_ENEMY_DEFAULT_ZONE: WorldZone = WorldZone::PilgrimsAscent;
 
 pub fn update_gltf_scene(
     mut commands: Commands,
@@ -48,40 +57,53 @@ pub fn update_gltf_scene(
     }
 }
 
+// 씬에서 찾은 적 배치 정보 - 순회가 끝난 뒤 순찰 경로와 묶어서 스폰 정의로 만듦
+#[derive(Default)]
+struct SceneSpawnMarkers {
+    enemies: Vec<(Entity, String, Transform)>,
+    patrol_points: HashMap<String, Vec<(usize, Vec3)>>,
+}
+
 // 씬 내의 특정 오브젝트들을 찾아서 게임 컴포넌트 추가
+// 씬 엔티티는 SceneRoot 가 추가된 다음 프레임 이후에 생성되므로 자식이 붙는 시점에 처리
 pub fn process_scene_objects(
     mut commands: Commands,
-    scene_query: Query<Entity, Added<SceneRoot>>,
+    scene_query: Query<(Entity, &Name), (With<SceneRoot>, Added<Children>)>,
     children_query: Query<&Children>,
     name_query: Query<&Name>,
     transform_query: Query<&Transform>,
-    mut processed: Local<bool>,
+    extras_query: Query<&GltfExtras>,
 ) {
-    if *processed {
-        return;
-    }
+    for (scene_entity, scene_name) in scene_query.iter() {
+        // 캐릭터/무기 모델 씬은 제외하고 레벨 씬만 처리
+        if !scene_name.as_str().starts_with("SceneRoot_") {
+            continue;
+        }
 
-    for scene_entity in scene_query.iter() {
         info!("Processing scene objects...");
+        let mut markers = SceneSpawnMarkers::default();
+        let root_transform = transform_query.get(scene_entity).copied().unwrap_or_default();
         process_entity_recursive(
             scene_entity,
+            root_transform,
             &mut commands,
             &children_query,
             &name_query,
             &transform_query,
-            0,
+            &mut markers,
         );
-        *processed = true;
+        spawn_scene_enemies(&mut commands, &extras_query, markers);
     }
 }
 
 fn process_entity_recursive(
     entity: Entity,
+    world_transform: Transform,
     commands: &mut Commands,
     children_query: &Query<&Children>,
     name_query: &Query<&Name>,
     transform_query: &Query<&Transform>,
-    depth: usize,
+    markers: &mut SceneSpawnMarkers,
 ) {
     // 엔티티 이름 확인
     if let Ok(name) = name_query.get(entity) {
@@ -91,22 +113,33 @@ fn process_entity_recursive(
         match name_str {
             n if n.contains("Player") || n.contains("player") => {
                 info!("Found player spawn point: {}", n);
-                if let Ok(transform) = transform_query.get(entity) {
-                    // 플레이어 스폰 위치로 사용
-                    commands.entity(entity).insert(PlayerSpawnPoint {
-                        position: transform.translation,
-                        rotation: transform.rotation,
-                    });
+                // 플레이어 스폰 위치로 사용
+                commands.entity(entity).insert(PlayerSpawnPoint {
+                    position: world_transform.translation,
+                    rotation: world_transform.rotation,
+                });
+            }
+            // 순찰 웨이포인트 - "<적 이름>_Patrol_<순서>" (예: Enemy_01_Patrol_0)
+            n if n.contains("_Patrol_") => {
+                let (owner, index) = n.rsplit_once("_Patrol_").unwrap_or((n, ""));
+                // Blender 가 중복 이름에 붙이는 ".001" 접미사는 무시
+                let index = index.split('.').next().unwrap_or(index);
+                match index.parse::<usize>() {
+                    Ok(index) => {
+                        info!("Found patrol point {} for {}", index, owner);
+                        markers
+                            .patrol_points
+                            .entry(owner.to_string())
+                            .or_default()
+                            .push((index, world_transform.translation));
+                    }
+                    Err(_) => warn!("Invalid patrol point name: {}", n),
                 }
             }
-            n if n.contains("Enemy") || n.contains("enemy") => {
+            // 적 배치 지점 - 정확히 "Enemy_<번호>" 인 빈 오브젝트만 (Enemy_01_mesh, EnemyGate 등은 제외)
+            n if is_enemy_spawn_name(n) => {
                 info!("Found enemy spawn point: {}", n);
-                if let Ok(transform) = transform_query.get(entity) {
-                    commands.entity(entity).insert(EnemySpawnPoint {
-                        position: transform.translation,
-                        rotation: transform.rotation,
-                    });
-                }
+                markers.enemies.push((entity, n.to_string(), world_transform));
             }
             n if n.contains("Checkpoint") || n.contains("checkpoint") => {
                 info!("Found checkpoint: {}", n);
@@ -123,28 +156,94 @@ fn process_entity_recursive(
     // 자식들도 재귀적으로 처리
     if let Ok(children) = children_query.get(entity) {
         for &child in children.iter() {
+            let local = transform_query.get(child).copied().unwrap_or_default();
             process_entity_recursive(
                 child,
+                world_transform.mul_transform(local),
                 commands,
                 children_query,
                 name_query,
                 transform_query,
-                depth + 1,
+                markers,
             );
         }
     }
 }
 
-// 마커 컴포넌트들
-#[derive(Component)]
-pub struct PlayerSpawnPoint {
-    pub position: Vec3,
-    pub rotation: Quat,
+fn is_enemy_spawn_name(name: &str) -> bool {
+    name.strip_prefix("Enemy_")
+        .is_some_and(|index| !index.is_empty() && index.chars().all(|c| c.is_ascii_digit()))
+}
+
+// 씬의 적 배치 지점을 스폰 정의로 - 같은 이름의 순찰 웨이포인트가 있으면 순서대로 경로 구성
+// 적 설정은 Blender 커스텀 프로퍼티 (glTF extras) 로 지정, 없는 값은 기본값 사용
+//   enemy_type = "Archer", zone = "SunkenCity", max_health = 80.0, max_poise = 30.0,
+//   soul_reward = 120, loot_table = "hollow_soldier", patrol_mode = "PingPong", patrol_wait = 3.0
+fn spawn_scene_enemies(commands: &mut Commands, extras_query: &Query<&GltfExtras>, mut markers: SceneSpawnMarkers) {
+    for (entity, name, transform) in markers.enemies {
+        let extras = extras_query
+            .get(entity)
+            .ok()
+            .and_then(|extras| serde_json::from_str::<serde_json::Value>(&extras.value).ok())
+            .unwrap_or_default();
+        let extra_f32 = |key: &str| extras.get(key).and_then(|value| value.as_f64()).map(|value| value as f32);
+
+        let patrol = markers.patrol_points.remove(&name).map(|mut points| {
+            points.sort_by_key(|(index, _)| *index);
+            let mut path = PatrolPath::new(points.into_iter().map(|(_, position)| position).collect(), PatrolMode::Loop);
+            if extras.get("patrol_mode").and_then(|mode| mode.as_str()) == Some("PingPong") {
+                path.mode = PatrolMode::PingPong;
+            }
+            if let Some(wait) = extra_f32("patrol_wait") {
+                path.wait_time = wait;
+            }
+            info!("Enemy {} patrols {} points ({:?})", name, path.points.len(), path.mode);
+            path
+        });
+
+        let mut enemy = match extras.get("enemy_type").and_then(|kind| kind.as_str()) {
+            Some("Archer") => Enemy::archer(),
+            Some("BasicMelee") | None => Enemy::default(),
+            Some(other) => {
+                warn!("Unknown enemy_type '{}' on {}, using BasicMelee", other, name);
+                Enemy::default()
+            }
+        };
+        if let Some(souls) = extras.get("soul_reward").and_then(|souls| souls.as_i64()) {
+            enemy.soul_reward = souls as i32;
+        }
+        if let Some(table) = extras.get("loot_table").and_then(|table| table.as_str()) {
+            enemy.loot_table = Some(table.to_string());
+        }
+
+        // 지역은 저항 프로필을 정함 - 지정하지 않으면 Z1
+        let zone = match extras.get("zone") {
+            Some(zone) => serde_json::from_value::<WorldZone>(zone.clone()).unwrap_or_else(|_| {
+                warn!("Unknown zone {} on {}, using {:?}", zone, name, SCENE_ENEMY_DEFAULT_ZONE);
+                SCENE_ENEMY_DEFAULT_ZONE
+            }),
+            None => SCENE_ENEMY_DEFAULT_ZONE,
+        };
+
+        commands.entity(entity).insert(EnemySpawnDefinition {
+            position: transform.translation,
+            rotation: transform.rotation,
+            enemy,
+            max_health: extra_f32("max_health").unwrap_or(SCENE_ENEMY_DEFAULT_HEALTH),
+            max_poise: extra_f32("max_poise").unwrap_or(SCENE_ENEMY_DEFAULT_POISE),
+            zone,
+            patrol,
+        });
+    }
+
+    for owner in markers.patrol_points.keys() {
+        warn!("Patrol points found for unknown enemy: {}", owner);
+    }
 }
 
+// 마커 컴포넌트들
 #[derive(Component)]
-pub struct EnemySpawnPoint {
+pub struct PlayerSpawnPoint {
     pub position: Vec3,
     pub rotation: Quat,
 }
-
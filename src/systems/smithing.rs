@@ -0,0 +1,314 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use crate::components::*;
+use crate::resources::*;
+use crate::states::GameState;
+use crate::systems::checkpoint::nearest_lit_checkpoint;
+use crate::systems::third_person_camera::ThirdPersonCamera;
+use crate::systems::ui::{menu_root, set_menu_cursor, MENU_ENTRY_COLOR, MENU_SELECTED_COLOR};
+
+// 내구도 감소량 - 적을 벤 경우 / 벽에 부딪힌 경우 / 가드로 막은 경우
+pub const DURABILITY_LOSS_PER_HIT: f32 = 1.0;
+pub const DURABILITY_LOSS_PER_WALL_HIT: f32 = 4.0;
+pub const DURABILITY_LOSS_PER_BLOCK: f32 = 2.5;
+
+// 강화 1단계당 기본 데미지 / 능력치 보정 증가율
+const UPGRADE_DAMAGE_PER_LEVEL: f32 = 0.1;
+const UPGRADE_SCALING_PER_LEVEL: f32 = 0.05;
+// 다 닳은 방패의 가드 흡수율 배율
+const BROKEN_SHIELD_GUARD_SCALE: f32 = 0.5;
+
+// +1 ~ +5 는 강화석, +6 ~ +10 은 상급 강화석
+const UPGRADE_MATERIAL: &str = "smithing_stone";
+const HEAVY_UPGRADE_MATERIAL: &str = "heavy_smithing_stone";
+const HEAVY_UPGRADE_FROM_LEVEL: u32 = 5;
+
+// 다음 단계 강화에 필요한 재료와 개수 - 같은 재료 구간 안에서 단계마다 1개씩 늘어남
+pub fn upgrade_cost(level: u32) -> (&'static str, u32) {
+    if level < HEAVY_UPGRADE_FROM_LEVEL {
+        (UPGRADE_MATERIAL, level + 1)
+    } else {
+        (HEAVY_UPGRADE_MATERIAL, level - HEAVY_UPGRADE_FROM_LEVEL + 1)
+    }
+}
+
+// 들고 있는 오른손 무기 내구도 감소 (맨손은 닳지 않음)
+pub fn wear_active_weapon(equipment: &Equipment, weapon: &Weapon, conditions: &mut WeaponConditions, amount: f32) {
+    if weapon.max_durability <= 0.0 {
+        return;
+    }
+    if let Some(item_id) = equipment.active_right_hand() {
+        conditions.wear(item_id, amount, weapon.max_durability);
+    }
+}
+
+// 가드로 막은 장비 내구도 감소 - 왼손 방패로 막았으면 방패, 아니면 오른손 무기
+pub fn wear_guarding_item(item_db: &ItemDatabase, equipment: &Equipment, weapon: &Weapon, conditions: &mut WeaponConditions, amount: f32) {
+    if let Some(item_id) = equipment.active_left_hand() {
+        if let Some(ItemKind::Shield(shield)) = item_db.get(item_id).map(|item| &item.kind) {
+            conditions.wear(item_id, amount, shield.max_durability);
+            return;
+        }
+    }
+    wear_active_weapon(equipment, weapon, conditions, amount);
+}
+
+// 든 무기의 강화 단계 / 내구도를 Weapon 에 반영 (apply_equipment 이후, 능력치 보정 이전)
+pub fn apply_weapon_condition(
+    item_db: Res<ItemDatabase>,
+    mut player_query: Query<
+        (&Equipment, &WeaponConditions, &mut Weapon),
+        (With<Player>, Or<(Changed<Equipment>, Changed<WeaponConditions>)>),
+    >,
+) {
+    for (equipment, conditions, mut weapon) in player_query.iter_mut() {
+        // 왼손 방패 - 다 닳으면 가드 흡수율 감소
+        if let Some(shield_id) = equipment.active_left_hand() {
+            if let Some(ItemKind::Shield(shield)) = item_db.get(shield_id).map(|item| &item.kind) {
+                let broken = conditions.get(shield_id).wear >= shield.max_durability;
+                let scale = if broken { BROKEN_SHIELD_GUARD_SCALE } else { 1.0 };
+                weapon.guard_absorption = shield.guard_absorption * scale;
+            }
+        }
+
+        let Some(item_id) = equipment.active_right_hand() else {
+            continue;
+        };
+        let Some(ItemKind::Weapon(data)) = item_db.get(item_id).map(|item| &item.kind) else {
+            continue;
+        };
+
+        let condition = conditions.get(item_id);
+        let level = condition.upgrade_level as f32;
+        let durability = (data.max_durability - condition.wear).max(0.0);
+        let was_broken = weapon.is_broken();
+
+        weapon.upgrade_level = condition.upgrade_level;
+        weapon.damage = data.damage * (1.0 + UPGRADE_DAMAGE_PER_LEVEL * level);
+        weapon.strength_scaling = data.strength_scaling * (1.0 + UPGRADE_SCALING_PER_LEVEL * level);
+        weapon.dexterity_scaling = data.dexterity_scaling * (1.0 + UPGRADE_SCALING_PER_LEVEL * level);
+        weapon.max_durability = data.max_durability;
+        weapon.durability = durability;
+
+        if weapon.is_broken() && !was_broken {
+            warn!("{} is broken!", item_db.name(item_id));
+        }
+    }
+}
+
+// 체크포인트 휴식 / 부활 / 빠른 이동 시 모든 무기 수리
+pub fn weapon_repair_system(
+    mut reset_events: EventReader<WorldResetEvent>,
+    mut player_query: Query<&mut WeaponConditions, With<Player>>,
+) {
+    if reset_events.read().count() == 0 {
+        return;
+    }
+
+    for mut conditions in player_query.iter_mut() {
+        if conditions.weapons.values().any(|condition| condition.wear > 0.0) {
+            conditions.repair_all();
+            info!("Weapons repaired");
+        }
+    }
+}
+
+// 점화한 체크포인트 근처에서 강화 키 - 가진 무기 목록으로 강화 메뉴 열기
+pub fn open_smithing_menu(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    input_mapping: Res<InputMapping>,
+    item_db: Res<ItemDatabase>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut smithing_menu: ResMut<SmithingMenuState>,
+    player_query: Query<(&Transform, &Health, &Inventory), With<Player>>,
+    checkpoint_query: Query<(Entity, &GlobalTransform, &Checkpoint)>,
+) {
+    if !keyboard_input.just_pressed(input_mapping.upgrade) {
+        return;
+    }
+
+    let Ok((player_transform, health, inventory)) = player_query.get_single() else {
+        return;
+    };
+    if health.current <= 0.0 {
+        return;
+    }
+
+    if nearest_lit_checkpoint(checkpoint_query.iter(), player_transform.translation).is_none() {
+        info!("Weapon upgrades are only available at a lit checkpoint");
+        return;
+    }
+
+    smithing_menu.weapons = inventory
+        .items
+        .iter()
+        .filter(|item| matches!(item_db.get(&item.item_id).map(|def| &def.kind), Some(ItemKind::Weapon(_))))
+        .map(|item| item.item_id.clone())
+        .collect();
+    if smithing_menu.weapons.is_empty() {
+        info!("No weapons to upgrade");
+        return;
+    }
+
+    smithing_menu.selected = 0;
+    next_state.set(GameState::Smithing);
+}
+
+pub fn setup_smithing_menu(
+    mut commands: Commands,
+    smithing_menu: Res<SmithingMenuState>,
+    mut window_q: Query<&mut Window, With<PrimaryWindow>>,
+    mut cam_q: Query<&mut ThirdPersonCamera>,
+) {
+    set_menu_cursor(&mut window_q, &mut cam_q, true);
+
+    commands.spawn((
+        menu_root(),
+        SmithingMenu,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new("UPGRADE WEAPON"),
+            TextFont {
+                font_size: 48.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+
+        // 보유 강화 재료 (매 프레임 갱신)
+        parent.spawn((
+            Text::new(""),
+            TextFont {
+                font_size: 22.0,
+                ..default()
+            },
+            TextColor(Color::srgb(1.0, 1.0, 0.0)),
+            Node {
+                margin: UiRect::bottom(Val::Px(12.0)),
+                ..default()
+            },
+            SmithingSummary,
+        ));
+
+        for index in 0..smithing_menu.weapons.len() {
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(MENU_ENTRY_COLOR),
+                SmithingEntry(index),
+            ));
+        }
+
+        parent.spawn((
+            Text::new("W/S to select, ENTER to upgrade, ESC to close"),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.6, 0.6, 0.6)),
+            Node {
+                margin: UiRect::top(Val::Px(24.0)),
+                ..default()
+            },
+        ));
+    });
+}
+
+// 강화 메뉴 조작 - 재료를 소모해 선택한 무기 +1
+pub fn smithing_menu_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    input_mapping: Res<InputMapping>,
+    item_db: Res<ItemDatabase>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut smithing_menu: ResMut<SmithingMenuState>,
+    mut player_query: Query<(&mut Inventory, &mut WeaponConditions), With<Player>>,
+    mut summary_query: Query<&mut Text, (With<SmithingSummary>, Without<SmithingEntry>)>,
+    mut entry_query: Query<(&SmithingEntry, &mut Text, &mut TextColor), Without<SmithingSummary>>,
+) {
+    if keyboard_input.just_pressed(input_mapping.pause) || keyboard_input.just_pressed(input_mapping.upgrade) {
+        next_state.set(GameState::Playing);
+        return;
+    }
+
+    let Ok((mut inventory, mut conditions)) = player_query.get_single_mut() else {
+        return;
+    };
+
+    let count = smithing_menu.weapons.len();
+    if count == 0 {
+        return;
+    }
+    if keyboard_input.any_just_pressed([input_mapping.move_forward, KeyCode::ArrowUp]) {
+        smithing_menu.selected = (smithing_menu.selected + count - 1) % count;
+    }
+    if keyboard_input.any_just_pressed([input_mapping.move_backward, KeyCode::ArrowDown]) {
+        smithing_menu.selected = (smithing_menu.selected + 1) % count;
+    }
+
+    if keyboard_input.just_pressed(KeyCode::Enter) {
+        let item_id = &smithing_menu.weapons[smithing_menu.selected];
+        let level = conditions.get(item_id).upgrade_level;
+        let (material, amount) = upgrade_cost(level);
+        if level >= MAX_WEAPON_UPGRADE_LEVEL {
+            info!("{} is already fully upgraded", item_db.name(item_id));
+        } else if !inventory.remove(material, amount) {
+            info!("Not enough {} ({} / {})", item_db.name(material), inventory.count(material), amount);
+        } else {
+            conditions.get_mut(item_id).upgrade_level += 1;
+            info!("Upgraded {} to +{}", item_db.name(item_id), level + 1);
+        }
+    }
+
+    for mut text in summary_query.iter_mut() {
+        text.0 = format!(
+            "{} x{}    {} x{}",
+            item_db.name(UPGRADE_MATERIAL),
+            inventory.count(UPGRADE_MATERIAL),
+            item_db.name(HEAVY_UPGRADE_MATERIAL),
+            inventory.count(HEAVY_UPGRADE_MATERIAL),
+        );
+    }
+
+    for (entry, mut text, mut color) in entry_query.iter_mut() {
+        let Some(item_id) = smithing_menu.weapons.get(entry.0) else {
+            continue;
+        };
+        let condition = conditions.get(item_id);
+        let max_durability = match item_db.get(item_id).map(|item| &item.kind) {
+            Some(ItemKind::Weapon(data)) => data.max_durability,
+            _ => 0.0,
+        };
+        let next = if condition.upgrade_level >= MAX_WEAPON_UPGRADE_LEVEL {
+            "MAX".to_string()
+        } else {
+            let (material, amount) = upgrade_cost(condition.upgrade_level);
+            format!("Next: {} x{}", item_db.name(material), amount)
+        };
+        text.0 = format!(
+            "{:<16} +{:<3} {:>3.0}/{:<3.0}  {}",
+            item_db.name(item_id),
+            condition.upgrade_level,
+            (max_durability - condition.wear).max(0.0),
+            max_durability,
+            next,
+        );
+        let selected = smithing_menu.selected == entry.0;
+        color.0 = if selected { MENU_SELECTED_COLOR } else { MENU_ENTRY_COLOR };
+    }
+}
+
+pub fn cleanup_smithing_menu(
+    mut commands: Commands,
+    menu_query: Query<Entity, With<SmithingMenu>>,
+    mut window_q: Query<&mut Window, With<PrimaryWindow>>,
+    mut cam_q: Query<&mut ThirdPersonCamera>,
+) {
+    set_menu_cursor(&mut window_q, &mut cam_q, false);
+
+    for entity in menu_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
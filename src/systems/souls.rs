@@ -0,0 +1,100 @@
+use bevy::prelude::*;
+use crate::components::*;
+
+// 혈흔에 이 거리 안으로 들어가면 소울 회수
+const SOUL_RETRIEVAL_RADIUS: f32 = 1.5;
+
+// 적 처치 시 처치한 쪽에 소울 지급
+pub fn soul_reward_system(
+    mut death_events: EventReader<DeathEvent>,
+    enemy_query: Query<&Enemy>,
+    mut souls_query: Query<&mut Souls>,
+) {
+    for event in death_events.read() {
+        let Ok(enemy) = enemy_query.get(event.entity) else {
+            continue;
+        };
+        let Some(mut souls) = event.killer.and_then(|killer| souls_query.get_mut(killer).ok()) else {
+            continue;
+        };
+
+        souls.count += enemy.soul_reward;
+        info!("Gained {} souls (total {})", enemy.soul_reward, souls.count);
+    }
+}
+
+// 플레이어 사망 시 가진 소울을 혈흔으로 떨어뜨림 - 회수 전에 다시 죽으면 이전 혈흔은 영구 소실
+pub fn soul_drop_system(
+    mut commands: Commands,
+    mut death_events: EventReader<DeathEvent>,
+    mut player_query: Query<&mut Souls, With<Player>>,
+    marker_query: Query<(Entity, &DeathMarker)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for event in death_events.read() {
+        let Ok(mut souls) = player_query.get_mut(event.entity) else {
+            continue;
+        };
+
+        for (marker_entity, marker) in marker_query.iter() {
+            info!("{} souls lost forever", marker.soul_amount);
+            commands.entity(marker_entity).despawn_recursive();
+        }
+
+        if souls.count <= 0 {
+            continue;
+        }
+
+        commands.spawn((
+            Mesh3d(meshes.add(Sphere::new(0.3).mesh().ico(2).unwrap())),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: Color::srgb(0.2, 0.9, 0.4),
+                emissive: LinearRgba::new(0.2, 1.5, 0.5, 1.0),
+                ..default()
+            })),
+            Transform::from_translation(event.position),
+            DeathMarker {
+                soul_amount: souls.count,
+                position: event.position,
+            },
+        )).with_children(|parent| {
+            parent.spawn((
+                PointLight {
+                    color: Color::srgb(0.2, 1.0, 0.4),
+                    intensity: 20000.0,
+                    range: 4.0,
+                    ..default()
+                },
+                Transform::from_xyz(0.0, 0.5, 0.0),
+            ));
+        });
+
+        info!("Dropped {} souls at {:?}", souls.count, event.position);
+        souls.count = 0;
+    }
+}
+
+// 혈흔에 닿으면 떨어뜨린 소울 회수
+pub fn soul_retrieval_system(
+    mut commands: Commands,
+    mut player_query: Query<(&Transform, &Health, &mut Souls), With<Player>>,
+    marker_query: Query<(Entity, &DeathMarker)>,
+) {
+    let Ok((player_transform, health, mut souls)) = player_query.get_single_mut() else {
+        return;
+    };
+    if health.current <= 0.0 {
+        return;
+    }
+
+    for (marker_entity, marker) in marker_query.iter() {
+        if player_transform.translation.distance(marker.position) > SOUL_RETRIEVAL_RADIUS {
+            continue;
+        }
+
+        souls.count += marker.soul_amount;
+        commands.entity(marker_entity).despawn_recursive();
+        info!("Retrieved {} souls", marker.soul_amount);
+    }
+}
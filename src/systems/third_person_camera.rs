@@ -44,6 +44,12 @@ pub struct ThirdPersonCamera {
     pub zoom: Zoom,
     /// The speed at which the scroll wheel zooms in or out.
     pub zoom_sensitivity: f32,
+    /// World position kept in frame while locked on. Disables mouse orbiting.
+    pub lock_on_focus: Option<Vec3>,
+    /// How far the camera tilts down while framing a lock-on target.
+    pub lock_on_pitch: f32,
+    /// The speed at which the camera turns toward a lock-on target.
+    pub lock_on_speed: f32,
 }
 
 impl Default for ThirdPersonCamera {
@@ -67,6 +73,9 @@ impl Default for ThirdPersonCamera {
             zoom_enabled: true,
             zoom: Zoom::new(5.0, 15.0), // 소울라이크 게임에 맞게 조정
             zoom_sensitivity: 1.0,
+            lock_on_focus: None,
+            lock_on_pitch: 0.35,
+            lock_on_speed: 8.0,
         }
     }
 }
@@ -155,6 +164,11 @@ pub fn orbit_mouse(
         return;
     }
 
+    // The lock-on framing drives the rotation instead
+    if cam.lock_on_focus.is_some() {
+        return;
+    }
+
     if cam.mouse_orbit_button_enabled && !mouse.pressed(cam.mouse_orbit_button) {
         return;
     }
@@ -189,6 +203,36 @@ pub fn orbit_mouse(
     cam_transform.translation = rot_matrix.mul_vec3(Vec3::new(0.0, 0.0, cam.zoom.radius));
 }
 
+/// Turn the camera so it looks past the player at the lock-on target
+pub fn frame_lock_on_target(
+    player_q: Query<&Transform, With<ThirdPersonCameraTarget>>,
+    mut cam_q: Query<(&ThirdPersonCamera, &mut Transform), Without<ThirdPersonCameraTarget>>,
+    time: Res<Time>,
+) {
+    let Ok(player) = player_q.get_single() else {
+        return;
+    };
+    let Ok((cam, mut cam_transform)) = cam_q.get_single_mut() else {
+        return;
+    };
+    let Some(focus) = cam.lock_on_focus else {
+        return;
+    };
+
+    let to_target = focus - player.translation;
+    let horizontal = Vec3::new(to_target.x, 0.0, to_target.z);
+    if horizontal.length_squared() < 0.01 {
+        return;
+    }
+
+    // Tilt down slightly so both the player and the target stay in view
+    let forward = (horizontal.normalize() - Vec3::Y * cam.lock_on_pitch).normalize();
+    let desired = Transform::default().looking_to(forward, Vec3::Y).rotation;
+    cam_transform.rotation = cam_transform
+        .rotation
+        .slerp(desired, (cam.lock_on_speed * time.delta_secs()).min(1.0));
+}
+
 /// Handle mouse wheel zoom
 pub fn zoom_mouse(
     mut scroll_evr: EventReader<MouseWheel>, 
@@ -1,7 +1,28 @@
 use bevy::prelude::*;
+use bevy::window::{CursorGrabMode, PrimaryWindow};
 use crate::components::*;
+use crate::resources::{BossDatabase, InputMapping, ItemDatabase, MerchantDatabase};
+use crate::systems::loot::{CHEST_INTERACT_RANGE, PICKUP_INTERACT_RANGE};
+use crate::systems::merchant::MERCHANT_INTERACT_RANGE;
+use crate::systems::third_person_camera::ThirdPersonCamera;
 use crate::states::*;
 
+const SOUL_COUNTER_COLOR: Color = Color::srgb(1.0, 1.0, 0.0);
+const SOUL_FLASH_DURATION: f32 = 0.6;
+const SOUL_ROLL_MIN_SPEED: f32 = 30.0;
+const FLASK_COUNTER_COLOR: Color = Color::srgb(1.0, 0.6, 0.2);
+const FLASK_EMPTY_COLOR: Color = Color::srgb(0.4, 0.4, 0.4);
+const DURABILITY_COLOR: Color = Color::srgb(0.8, 0.8, 0.8);
+const DURABILITY_LOW_COLOR: Color = Color::srgb(1.0, 0.5, 0.1);
+const WEAPON_BROKEN_COLOR: Color = Color::srgb(0.9, 0.2, 0.2);
+// 이 비율 아래로 내려가면 내구도 바를 경고색으로 표시
+const DURABILITY_LOW_RATIO: f32 = 0.3;
+const ITEM_NOTIFICATION_DURATION: f32 = 3.0;
+const ITEM_NOTIFICATION_FADE: f32 = 0.5;  // 사라지기 전 흐려지는 시간
+const BOSS_BANNER_COLOR: Color = Color::srgb(1.0, 0.85, 0.4);
+const BOSS_BANNER_DURATION: f32 = 5.0;
+const BOSS_BANNER_FADE: f32 = 1.5;
+
 pub fn setup_ui(mut commands: Commands) {
     // 메인 UI 컨테이너
     commands.spawn((
@@ -20,7 +41,7 @@ pub fn setup_ui(mut commands: Commands) {
         parent.spawn((
             Node {
                 width: Val::Percent(100.0),
-                height: Val::Px(120.0),
+                height: Val::Px(150.0),
                 flex_direction: FlexDirection::Column,
                 justify_content: JustifyContent::Start,
                 align_items: AlignItems::Start,
@@ -29,27 +50,46 @@ pub fn setup_ui(mut commands: Commands) {
             },
             BackgroundColor(Color::NONE),
         )).with_children(|parent| {
-            // 체력바 컨테이너
-            parent.spawn((
-                Node {
-                    width: Val::Px(300.0),
-                    height: Val::Px(30.0),
-                    margin: UiRect::bottom(Val::Px(10.0)),
-                    border: UiRect::all(Val::Px(2.0)),
-                    ..default()
-                },
-                BorderColor(Color::WHITE),
-                BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
-            )).with_children(|parent| {
-                // 체력바
+            // 체력바 + 회복 물약 줄
+            parent.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(12.0),
+                margin: UiRect::bottom(Val::Px(10.0)),
+                ..default()
+            }).with_children(|parent| {
+                // 체력바 컨테이너
                 parent.spawn((
                     Node {
-                        width: Val::Percent(100.0),
-                        height: Val::Percent(100.0),
+                        width: Val::Px(300.0),
+                        height: Val::Px(30.0),
+                        border: UiRect::all(Val::Px(2.0)),
                         ..default()
                     },
-                    BackgroundColor(Color::srgb(0.8, 0.2, 0.2)),
-                    HealthBar,
+                    BorderColor(Color::WHITE),
+                    BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
+                )).with_children(|parent| {
+                    // 체력바
+                    parent.spawn((
+                        Node {
+                            width: Val::Percent(100.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.8, 0.2, 0.2)),
+                        HealthBar,
+                    ));
+                });
+
+                // 회복 물약 남은 횟수
+                parent.spawn((
+                    Text::new("Flask 0/0"),
+                    TextFont {
+                        font_size: 20.0,
+                        ..default()
+                    },
+                    TextColor(FLASK_COUNTER_COLOR),
+                    FlaskCounter,
                 ));
             });
 
@@ -75,6 +115,46 @@ pub fn setup_ui(mut commands: Commands) {
                     StaminaBar,
                 ));
             });
+
+            // 든 무기 + 내구도
+            parent.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(10.0),
+                margin: UiRect::top(Val::Px(8.0)),
+                ..default()
+            }).with_children(|parent| {
+                parent.spawn((
+                    Text::new(""),
+                    TextFont {
+                        font_size: 18.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                    WeaponStatus,
+                ));
+
+                parent.spawn((
+                    Node {
+                        width: Val::Px(100.0),
+                        height: Val::Px(8.0),
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    BorderColor(Color::WHITE),
+                    BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
+                )).with_children(|parent| {
+                    parent.spawn((
+                        Node {
+                            width: Val::Percent(100.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(DURABILITY_COLOR),
+                        DurabilityBar,
+                    ));
+                });
+            });
         });
 
         // 우상단 UI (소울 카운터)
@@ -97,10 +177,107 @@ pub fn setup_ui(mut commands: Commands) {
                     font_size: 24.0,
                     ..default()
                 },
-                TextColor(Color::srgb(1.0, 1.0, 0.0)),
+                TextColor(SOUL_COUNTER_COLOR),
                 SoulCounter,
+                SoulCounterAnimation::default(),
             ));
         });
+
+        // 중앙 하단 (상호작용 안내, 아이템 획득 알림) - 레이아웃에 끼지 않도록 절대 위치
+        parent.spawn(Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(80.0),
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(8.0),
+            ..default()
+        }).with_children(|parent| {
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 26.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.9, 0.6)),
+                ItemNotification::default(),
+            ));
+
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                InteractionPrompt,
+            ));
+        });
+
+        // 화면 중앙 보스 처치 배너
+        parent.spawn(Node {
+            position_type: PositionType::Absolute,
+            top: Val::Percent(40.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        }).with_children(|parent| {
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 56.0,
+                    ..default()
+                },
+                TextColor(BOSS_BANNER_COLOR.with_alpha(0.0)),
+                BossDefeatedBanner::default(),
+            ));
+        });
+
+        // 화면 하단 보스 이름 + 체력바 - 보스전 중에만 표시
+        parent.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(24.0),
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(4.0),
+                display: Display::None,
+                ..default()
+            },
+            BossHealthBarRoot,
+        )).with_children(|parent| {
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                BossNameText,
+            ));
+
+            parent.spawn((
+                Node {
+                    width: Val::Percent(60.0),
+                    height: Val::Px(14.0),
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                BorderColor(Color::srgb(0.6, 0.5, 0.3)),
+                BackgroundColor(Color::srgb(0.15, 0.15, 0.15)),
+            )).with_children(|parent| {
+                parent.spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.6, 0.1, 0.1)),
+                    BossHealthBar,
+                ));
+            });
+        });
     });
 }
 
@@ -128,46 +305,283 @@ pub fn update_stamina_ui(
     }
 }
 
+pub fn update_flask_ui(
+    player_query: Query<&HealingFlask, (With<Player>, Changed<HealingFlask>)>,
+    mut flask_counter_query: Query<(&mut Text, &mut TextColor), With<FlaskCounter>>,
+) {
+    for flask in player_query.iter() {
+        for (mut text, mut color) in flask_counter_query.iter_mut() {
+            text.0 = format!("Flask {}/{}", flask.charges, flask.max_charges);
+            color.0 = if flask.charges > 0 { FLASK_COUNTER_COLOR } else { FLASK_EMPTY_COLOR };
+        }
+    }
+}
+
+pub fn update_weapon_ui(
+    item_db: Res<ItemDatabase>,
+    player_query: Query<(&Weapon, &Equipment), (With<Player>, Changed<Weapon>)>,
+    mut status_query: Query<(&mut Text, &mut TextColor), With<WeaponStatus>>,
+    mut bar_query: Query<(&mut Node, &mut BackgroundColor), With<DurabilityBar>>,
+) {
+    for (weapon, equipment) in player_query.iter() {
+        let name = equipment.active_right_hand().map_or("Unarmed", |item_id| item_db.name(item_id));
+        for (mut text, mut color) in status_query.iter_mut() {
+            text.0 = if weapon.upgrade_level > 0 {
+                format!("{} +{}", name, weapon.upgrade_level)
+            } else {
+                name.to_string()
+            };
+            if weapon.is_broken() {
+                text.0.push_str(" (Broken)");
+            }
+            color.0 = if weapon.is_broken() { WEAPON_BROKEN_COLOR } else { Color::WHITE };
+        }
+
+        // 맨손은 내구도가 없으므로 가득 찬 바로 표시
+        let ratio = if weapon.max_durability > 0.0 {
+            (weapon.durability / weapon.max_durability).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        for (mut node, mut background) in bar_query.iter_mut() {
+            node.width = Val::Percent(ratio * 100.0);
+            background.0 = if ratio < DURABILITY_LOW_RATIO { DURABILITY_LOW_COLOR } else { DURABILITY_COLOR };
+        }
+    }
+}
+
+// 보스전 중인 보스의 이름과 체력 표시, 전투가 끝나면 숨김
+pub fn update_boss_health_ui(
+    boss_db: Res<BossDatabase>,
+    mut encounter_events: EventReader<BossEncounterStartedEvent>,
+    boss_query: Query<(&BossState, &Health), Without<Dead>>,
+    mut root_query: Query<&mut Node, (With<BossHealthBarRoot>, Without<BossHealthBar>)>,
+    mut name_query: Query<&mut Text, With<BossNameText>>,
+    mut bar_query: Query<&mut Node, (With<BossHealthBar>, Without<BossHealthBarRoot>)>,
+) {
+    // 보스전이 시작될 때 이름 설정
+    for event in encounter_events.read() {
+        let name = boss_db.get(&event.boss_id).map_or(event.boss_id.as_str(), |definition| definition.name.as_str());
+        for mut text in name_query.iter_mut() {
+            text.0 = name.to_string();
+        }
+    }
+
+    let active = boss_query.iter().find(|(boss, _)| boss.active);
+
+    for mut node in root_query.iter_mut() {
+        let display = if active.is_some() { Display::Flex } else { Display::None };
+        if node.display != display {
+            node.display = display;
+        }
+    }
+
+    if let Some((_, health)) = active {
+        for mut node in bar_query.iter_mut() {
+            node.width = Val::Percent((health.current / health.max).clamp(0.0, 1.0) * 100.0);
+        }
+    }
+}
+
+// 보스 처치 배너 - 잠시 떠 있다가 흐려지며 사라짐
+pub fn update_boss_defeated_banner(
+    time: Res<Time>,
+    boss_db: Res<BossDatabase>,
+    mut defeated_events: EventReader<BossDefeatedEvent>,
+    mut banner_query: Query<(&mut Text, &mut TextColor, &mut BossDefeatedBanner)>,
+) {
+    let defeated = defeated_events.read().last().map(|event| {
+        boss_db.get(&event.boss_id).map_or(event.boss_id.clone(), |definition| definition.name.clone())
+    });
+
+    for (mut text, mut color, mut banner) in banner_query.iter_mut() {
+        if let Some(name) = &defeated {
+            text.0 = format!("{} FELLED", name.to_uppercase());
+            banner.timer = BOSS_BANNER_DURATION;
+        }
+        if banner.timer <= 0.0 {
+            continue;
+        }
+
+        banner.timer -= time.delta_secs();
+        let alpha = (banner.timer / BOSS_BANNER_FADE).clamp(0.0, 1.0);
+        color.0 = BOSS_BANNER_COLOR.with_alpha(alpha);
+        if banner.timer <= 0.0 {
+            text.0.clear();
+        }
+    }
+}
+
+// 가장 가까운 아이템 / 닫힌 상자 상호작용 안내
+pub fn update_interaction_prompt(
+    input_mapping: Res<InputMapping>,
+    item_db: Res<ItemDatabase>,
+    player_query: Query<(&Transform, &Health), With<Player>>,
+    pickup_query: Query<(&GlobalTransform, &ItemPickup)>,
+    chest_query: Query<(&GlobalTransform, &Chest)>,
+    merchant_query: Query<(&GlobalTransform, &Merchant)>,
+    merchant_db: Res<MerchantDatabase>,
+    mut prompt_query: Query<&mut Text, With<InteractionPrompt>>,
+) {
+    let Ok((player_transform, health)) = player_query.get_single() else {
+        return;
+    };
+    let player_position = player_transform.translation;
+
+    let pickup = pickup_query
+        .iter()
+        .map(|(transform, pickup)| (pickup, transform.translation().distance(player_position)))
+        .filter(|(_, distance)| *distance <= PICKUP_INTERACT_RANGE)
+        .min_by(|a, b| a.1.total_cmp(&b.1));
+    let chest_nearby = chest_query
+        .iter()
+        .any(|(transform, chest)| !chest.opened && transform.translation().distance(player_position) <= CHEST_INTERACT_RANGE);
+    let merchant = merchant_query
+        .iter()
+        .find(|(transform, _)| transform.translation().distance(player_position) <= MERCHANT_INTERACT_RANGE)
+        .and_then(|(_, merchant)| merchant_db.get(&merchant.merchant_id));
+
+    let key = format!("{:?}", input_mapping.interact).trim_start_matches("Key").to_string();
+    let prompt = if health.current <= 0.0 {
+        String::new()
+    } else if let Some((pickup, _)) = pickup {
+        format!("[{}] Pick up {}", key, item_db.name(&pickup.item_id))
+    } else if chest_nearby {
+        format!("[{}] Open chest", key)
+    } else if let Some(merchant) = merchant {
+        format!("[{}] Talk to {}", key, merchant.name)
+    } else {
+        String::new()
+    };
+
+    for mut text in prompt_query.iter_mut() {
+        // 같은 내용이면 쓰지 않음 (매 프레임 텍스트 레이아웃 갱신 방지)
+        if text.0 != prompt {
+            text.0 = prompt.clone();
+        }
+    }
+}
+
+// 아이템 획득 알림 - 같은 프레임에 얻은 아이템은 한 줄로 묶고, 잠시 뒤 흐려지며 사라짐
+pub fn update_item_notification(
+    time: Res<Time>,
+    item_db: Res<ItemDatabase>,
+    mut acquired_events: EventReader<ItemAcquiredEvent>,
+    mut notification_query: Query<(&mut Text, &mut TextColor, &mut ItemNotification)>,
+) {
+    let acquired: Vec<String> = acquired_events
+        .read()
+        .map(|event| {
+            if event.quantity > 1 {
+                format!("{} x{}", item_db.name(&event.item_id), event.quantity)
+            } else {
+                item_db.name(&event.item_id).to_string()
+            }
+        })
+        .collect();
+
+    for (mut text, mut color, mut notification) in notification_query.iter_mut() {
+        if !acquired.is_empty() {
+            text.0 = format!("Acquired: {}", acquired.join(", "));
+            notification.timer = ITEM_NOTIFICATION_DURATION;
+        }
+
+        if notification.timer <= 0.0 {
+            continue;
+        }
+        notification.timer -= time.delta_secs();
+        if notification.timer <= 0.0 {
+            text.0.clear();
+        }
+        color.0.set_alpha((notification.timer / ITEM_NOTIFICATION_FADE).clamp(0.0, 1.0));
+    }
+}
+
+// 획득/소실 시 숫자를 굴리고 색을 번쩍임
 pub fn update_souls_ui(
-    player_query: Query<&Souls, (With<Player>, Changed<Souls>)>,
-    mut soul_counter_query: Query<&mut Text, With<SoulCounter>>,
+    time: Res<Time>,
+    player_query: Query<&Souls, With<Player>>,
+    mut soul_counter_query: Query<(&mut Text, &mut TextColor, &mut SoulCounterAnimation), With<SoulCounter>>,
 ) {
-    for souls in player_query.iter() {
-        for mut text in soul_counter_query.iter_mut() {
-            text.0 = format!("Souls: {}", souls.count);
+    let Ok(souls) = player_query.get_single() else {
+        return;
+    };
+    let delta = time.delta_secs();
+
+    for (mut text, mut color, mut animation) in soul_counter_query.iter_mut() {
+        if souls.count != animation.last_count {
+            animation.gained = souls.count > animation.last_count;
+            animation.last_count = souls.count;
+            animation.flash_timer = SOUL_FLASH_DURATION;
         }
+
+        // 남은 차이에 비례해서 빠르게, 최소 초당 SOUL_ROLL_MIN_SPEED 만큼 굴러감
+        let target = souls.count as f32;
+        let difference = target - animation.displayed;
+        let step = (difference.abs() * 6.0).max(SOUL_ROLL_MIN_SPEED) * delta;
+        animation.displayed = if difference.abs() <= step {
+            target
+        } else {
+            animation.displayed + step * difference.signum()
+        };
+
+        animation.flash_timer = (animation.flash_timer - delta).max(0.0);
+        let flash = animation.flash_timer / SOUL_FLASH_DURATION;
+        let flash_color = if animation.gained { Color::WHITE } else { Color::srgb(1.0, 0.2, 0.2) };
+        color.0 = SOUL_COUNTER_COLOR.mix(&flash_color, flash);
+
+        text.0 = format!("Souls: {}", animation.displayed.round() as i32);
     }
 }
 
-pub fn setup_pause_menu(
-    mut commands: Commands, 
-    current_state: Res<State<GameState>>,
-    mut window_q: Query<&mut Window, With<bevy::window::PrimaryWindow>>,
-    mut cam_q: Query<&mut crate::systems::third_person_camera::ThirdPersonCamera>,
+// 메뉴 공통 - 항목 색
+pub const MENU_ENTRY_COLOR: Color = Color::srgb(0.7, 0.7, 0.7);
+pub const MENU_SELECTED_COLOR: Color = Color::srgb(1.0, 0.8, 0.3);
+
+// 메뉴가 열려 있는 동안 커서를 보이고 카메라 회전을 멈춤, 닫히면 다시 잠금
+pub fn set_menu_cursor(
+    window_q: &mut Query<&mut Window, With<PrimaryWindow>>,
+    cam_q: &mut Query<&mut ThirdPersonCamera>,
+    menu_open: bool,
 ) {
-    // 일시정지 시 커서 보이기
     if let Ok(mut window) = window_q.get_single_mut() {
-        window.cursor_options.grab_mode = bevy::window::CursorGrabMode::None;
-        window.cursor_options.visible = true;
+        window.cursor_options.grab_mode = if menu_open { CursorGrabMode::None } else { CursorGrabMode::Locked };
+        window.cursor_options.visible = menu_open;
     }
-    
-    // 카메라 커서 락 해제
     if let Ok(mut cam) = cam_q.get_single_mut() {
-        cam.cursor_lock_active = false;
+        cam.cursor_lock_active = !menu_open;
     }
+}
+
+// 화면 전체를 어둡게 덮고 가운데에 항목을 세로로 쌓는 메뉴 루트
+pub fn menu_root() -> (Node, BackgroundColor, GlobalZIndex) {
+    (
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(6.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+        GlobalZIndex(10),
+    )
+}
+
+pub fn setup_pause_menu(
+    mut commands: Commands, 
+    current_state: Res<State<GameState>>,
+    mut window_q: Query<&mut Window, With<PrimaryWindow>>,
+    mut cam_q: Query<&mut ThirdPersonCamera>,
+) {
+    // 일시정지 시 커서 보이기
+    set_menu_cursor(&mut window_q, &mut cam_q, true);
     
     if *current_state.get() == GameState::Paused {
         commands.spawn((
-            Node {
-                width: Val::Percent(100.0),
-                height: Val::Percent(100.0),
-                justify_content: JustifyContent::Center,
-                align_items: AlignItems::Center,
-                flex_direction: FlexDirection::Column,
-                ..default()
-            },
-            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
-            GlobalZIndex(10),
+            menu_root(),
             PauseMenu,
         )).with_children(|parent| {
                 // 일시정지 텍스트
@@ -195,19 +609,11 @@ pub fn setup_pause_menu(
 pub fn cleanup_pause_menu(
     mut commands: Commands,
     pause_menu_query: Query<Entity, With<PauseMenu>>,
-    mut window_q: Query<&mut Window, With<bevy::window::PrimaryWindow>>,
-    mut cam_q: Query<&mut crate::systems::third_person_camera::ThirdPersonCamera>,
+    mut window_q: Query<&mut Window, With<PrimaryWindow>>,
+    mut cam_q: Query<&mut ThirdPersonCamera>,
 ) {
     // 일시정지 해제 시 커서 다시 잠그기
-    if let Ok(mut window) = window_q.get_single_mut() {
-        window.cursor_options.grab_mode = bevy::window::CursorGrabMode::Locked;
-        window.cursor_options.visible = false;
-    }
-    
-    // 카메라 커서 락 활성화
-    if let Ok(mut cam) = cam_q.get_single_mut() {
-        cam.cursor_lock_active = true;
-    }
+    set_menu_cursor(&mut window_q, &mut cam_q, false);
     
     for entity in pause_menu_query.iter() {
         commands.entity(entity).despawn_recursive();
@@ -18,7 +18,7 @@ pub struct WorldObject {
     pub zone: WorldZone,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum WorldZone {
     PilgrimsAscent,     // Z1
     CrystalizedForest,  // Z2
@@ -28,6 +28,19 @@ pub enum WorldZone {
     CrownsHeart,        // Z6
 }
 
+impl WorldZone {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            WorldZone::PilgrimsAscent => "Pilgrim's Ascent",
+            WorldZone::CrystalizedForest => "Crystalized Forest",
+            WorldZone::SunkenCity => "Sunken City",
+            WorldZone::GravityMines => "Gravity Mines",
+            WorldZone::GildedFortress => "Gilded Fortress",
+            WorldZone::CrownsHeart => "Crown's Heart",
+        }
+    }
+}
+
 #[derive(Resource)]
 pub struct WorldGenConfig {
     pub world_size: f32,        // 4000.0 meters (4km)
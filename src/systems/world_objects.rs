@@ -3,6 +3,8 @@ use bevy_rapier3d::prelude::*;
 use serde::{Deserialize, Serialize};
 use rand::prelude::*;
 use rand::rngs::ThreadRng;
+use crate::components::{ArenaPhaseElement, BossArena, CheckpointMarker, Chest, ChestLid, FogWall, Merchant};
+use crate::systems::loot::spawn_item_pickup;
 use crate::systems::world_generation::{WorldZone, WorldObject, MaterialDatabase};
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -308,6 +310,25 @@ fn create_object_database() -> Vec<ObjectMetadata> {
         },
     });
     
+    objects.push(ObjectMetadata {
+        object_id: "treasure_chest".to_string(),
+        display_name: "Treasure Chest".to_string(),
+        object_type: ObjectType::Chest,
+        material_id: "wood_old".to_string(),
+        scale: [1.2, 0.7, 0.8],
+        physics: PhysicsMetadata {
+            has_collision: true,
+            is_static: true,
+            collision_shape: CollisionShape::Box { half_extents: [0.6, 0.35, 0.4] },
+        },
+        zone_specific: ZoneSpecificData {
+            emissive_strength: 0.0,
+            float_height: None,
+            submerged_depth: None,
+            crystal_corruption: 0.0,
+        },
+    });
+    
     objects.push(ObjectMetadata {
         object_id: "dead_tree_01".to_string(),
         display_name: "Withered Tree".to_string(),
@@ -591,39 +612,61 @@ fn spawn_pilgrims_ascent_objects(
     let mut rng = rand::thread_rng();
     
     // Spawn checkpoint at entrance
-    if let Some(checkpoint_meta) = object_db.objects.iter()
-        .find(|obj| obj.object_id == "checkpoint_torch") {
-        
-        let mesh_handle = meshes.add(Cylinder::new(0.5, 3.0));
-        let material = create_material_from_metadata(checkpoint_meta, material_db, materials);
-        
-        commands.spawn((
-            Mesh3d(mesh_handle),
-            MeshMaterial3d(material),
-            Transform::from_xyz(offset.x + 0.0, offset.y + 1.5, offset.z - 100.0),
-            WorldObject {
-                object_type: checkpoint_meta.object_id.clone(),
-                material_id: checkpoint_meta.material_id.clone(),
-                zone: WorldZone::PilgrimsAscent,
-            },
-            create_collider(&checkpoint_meta.physics),
-            RigidBody::Fixed,
-        ))
-        .with_children(|parent| {
-            // Add flame light
-            parent.spawn((
-                PointLight {
-                    intensity: 5000.0,
-                    color: Color::srgb(1.0, 0.6, 0.2),
-                    range: 20.0,
-                    shadows_enabled: true,
-                    ..default()
-                },
-                Transform::from_xyz(0.0, 2.0, 0.0),
-            ));
-        });
+    spawn_checkpoint(
+        commands,
+        meshes,
+        materials,
+        object_db,
+        material_db,
+        offset + Vec3::new(0.0, 0.0, -100.0),
+        WorldZone::PilgrimsAscent,
+        "Pilgrim's Torch",
+    );
+    
+    // Treasure chests and loose items along the ascent
+    spawn_chest(
+        commands,
+        meshes,
+        materials,
+        object_db,
+        material_db,
+        offset + Vec3::new(-6.0, 0.0, -12.0),
+        0.4,
+        WorldZone::PilgrimsAscent,
+        "chest_pilgrims_ascent_gate",
+        &[("smithing_stone", 3), ("ring_of_burden", 1)],
+    );
+    spawn_chest(
+        commands,
+        meshes,
+        materials,
+        object_db,
+        material_db,
+        offset + Vec3::new(28.0, 0.0, -88.0),
+        -1.2,
+        WorldZone::PilgrimsAscent,
+        "chest_pilgrims_ascent_ruins",
+        &[("flask_shard", 1), ("smithing_stone", 2)],
+    );
+    for (position, item_id, quantity, loot_id) in [
+        (Vec3::new(4.0, 0.0, -8.0), "smithing_stone", 1, "pickup_pilgrims_ascent_01"),
+        (Vec3::new(-12.0, 0.0, -55.0), "flask_shard", 1, "pickup_pilgrims_ascent_02"),
+        (Vec3::new(22.0, 0.0, -35.0), "smithing_stone", 2, "pickup_pilgrims_ascent_03"),
+    ] {
+        spawn_item_pickup(commands, meshes, materials, offset + position, item_id, quantity, Some(loot_id.to_string()));
     }
     
+    // Merchant beside the entrance torch
+    spawn_merchant(
+        commands,
+        meshes,
+        materials,
+        offset + Vec3::new(10.0, 0.0, -94.0),
+        -2.2,
+        "pilgrim_merchant",
+        "Hooded Pilgrim",
+    );
+    
     // Spawn large rocks strategically placed
     if let Some(rock_meta) = object_db.objects.iter()
         .find(|obj| obj.object_id == "rock_large_01") {
@@ -1030,6 +1073,278 @@ fn spawn_pilgrims_ascent_objects(
     }
 }
 
+// Checkpoint torch on a small stone dais so there is always safe ground to stand on
+// (fast travel places the player next to it, even in zones without terrain)
+fn spawn_checkpoint(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    object_db: &ObjectDatabase,
+    material_db: &MaterialDatabase,
+    ground: Vec3,
+    zone: WorldZone,
+    name: &str,
+) {
+    let Some(checkpoint_meta) = object_db.objects.iter()
+        .find(|obj| obj.object_id == "checkpoint_torch") else {
+        return;
+    };
+    
+    let material = create_material_from_metadata(checkpoint_meta, material_db, materials);
+    
+    // Dais
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(8.0, 0.5, 8.0))),
+        MeshMaterial3d(material.clone()),
+        Transform::from_xyz(ground.x, ground.y - 0.25, ground.z),
+        RigidBody::Fixed,
+        Collider::cuboid(4.0, 0.25, 4.0),
+    ));
+    
+    commands.spawn((
+        Mesh3d(meshes.add(Cylinder::new(0.5, 3.0))),
+        MeshMaterial3d(material),
+        Transform::from_xyz(ground.x, ground.y + 1.5, ground.z),
+        WorldObject {
+            object_type: checkpoint_meta.object_id.clone(),
+            material_id: checkpoint_meta.material_id.clone(),
+            zone,
+        },
+        create_collider(&checkpoint_meta.physics),
+        RigidBody::Fixed,
+        CheckpointMarker,
+        Name::new(name.to_string()),
+    ))
+    .with_children(|parent| {
+        // Add flame light
+        parent.spawn((
+            PointLight {
+                intensity: 5000.0,
+                color: Color::srgb(1.0, 0.6, 0.2),
+                range: 20.0,
+                shadows_enabled: true,
+                ..default()
+            },
+            Transform::from_xyz(0.0, 2.0, 0.0),
+        ));
+    });
+}
+
+// Treasure chest with a hinged lid (the lid swings open when looted, see systems::loot)
+fn spawn_chest(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    object_db: &ObjectDatabase,
+    material_db: &MaterialDatabase,
+    ground: Vec3,
+    rotation: f32,
+    zone: WorldZone,
+    loot_id: &str,
+    contents: &[(&str, u32)],
+) {
+    let Some(chest_meta) = object_db.objects.iter()
+        .find(|obj| obj.object_id == "treasure_chest") else {
+        return;
+    };
+    
+    let material = create_material_from_metadata(chest_meta, material_db, materials);
+    let [width, height, depth] = chest_meta.scale;
+    let lid_height = 0.25;
+    
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(width, height, depth))),
+        MeshMaterial3d(material.clone()),
+        Transform::from_xyz(ground.x, ground.y + height / 2.0, ground.z)
+            .with_rotation(Quat::from_rotation_y(rotation)),
+        WorldObject {
+            object_type: chest_meta.object_id.clone(),
+            material_id: chest_meta.material_id.clone(),
+            zone,
+        },
+        create_collider(&chest_meta.physics),
+        RigidBody::Fixed,
+        Chest {
+            loot_id: loot_id.to_string(),
+            contents: contents.iter().map(|(item_id, quantity)| (item_id.to_string(), *quantity)).collect(),
+            opened: false,
+        },
+        Name::new(chest_meta.display_name.clone()),
+    ))
+    .with_children(|parent| {
+        // Hinge on the back top edge, lid extends forward from it
+        parent.spawn((
+            Transform::from_xyz(0.0, height / 2.0, -depth / 2.0),
+            Visibility::default(),
+            ChestLid::default(),
+        ))
+        .with_children(|hinge| {
+            hinge.spawn((
+                Mesh3d(meshes.add(Cuboid::new(width, lid_height, depth))),
+                MeshMaterial3d(material),
+                Transform::from_xyz(0.0, lid_height / 2.0, depth / 2.0),
+            ));
+        });
+    });
+}
+
+// Circular boss arena: floor disc, a ring of fog wall segments (hidden until the arena seals,
+// see systems::boss) and the arena marker the boss spawn definition is created from
+fn spawn_boss_arena(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    center: Vec3,
+    radius: f32,
+    zone: WorldZone,
+    boss_id: &str,
+    floor_color: Color,
+) {
+    const FOG_WALL_SEGMENTS: usize = 24;
+    const FOG_WALL_HEIGHT: f32 = 8.0;
+    
+    // Floor
+    commands.spawn((
+        Mesh3d(meshes.add(Cylinder::new(radius, 1.0))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: floor_color,
+            perceptual_roughness: 0.85,
+            ..default()
+        })),
+        Transform::from_xyz(center.x, center.y - 0.5, center.z),
+        RigidBody::Fixed,
+        Collider::cylinder(0.5, radius),
+    ));
+    
+    commands.spawn((
+        Transform::from_translation(center),
+        Visibility::default(),
+        BossArena {
+            boss_id: boss_id.to_string(),
+            radius,
+            zone,
+            sealed: false,
+        },
+        Name::new(format!("BossArena_{}", boss_id)),
+    ));
+    
+    let fog = materials.add(StandardMaterial {
+        base_color: Color::srgba(0.85, 0.85, 0.9, 0.35),
+        emissive: LinearRgba::new(0.3, 0.3, 0.35, 1.0),
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        ..default()
+    });
+    // Segments slightly overlap so there are no gaps to slip through
+    let segment_length = std::f32::consts::TAU * radius / FOG_WALL_SEGMENTS as f32 * 1.1;
+    let segment_mesh = meshes.add(Cuboid::new(segment_length, FOG_WALL_HEIGHT, 0.4));
+    for i in 0..FOG_WALL_SEGMENTS {
+        let angle = i as f32 * std::f32::consts::TAU / FOG_WALL_SEGMENTS as f32;
+        let position = center + Vec3::new(angle.cos() * radius, FOG_WALL_HEIGHT / 2.0, angle.sin() * radius);
+        commands.spawn((
+            Mesh3d(segment_mesh.clone()),
+            MeshMaterial3d(fog.clone()),
+            // Face the segment's long side along the ring tangent
+            Transform::from_translation(position)
+                .with_rotation(Quat::from_rotation_y(-angle - std::f32::consts::FRAC_PI_2)),
+            Visibility::Hidden,
+            RigidBody::Fixed,
+            Collider::cuboid(segment_length / 2.0, FOG_WALL_HEIGHT / 2.0, 0.2),
+            ColliderDisabled,
+            FogWall {
+                boss_id: boss_id.to_string(),
+            },
+        ));
+    }
+}
+
+// Arena geometry that stays hidden until the boss reaches the given phase
+fn spawn_arena_phase_element(
+    commands: &mut Commands,
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+    transform: Transform,
+    collider: Collider,
+    boss_id: &str,
+    phase: usize,
+) {
+    commands.spawn((
+        Mesh3d(mesh),
+        MeshMaterial3d(material),
+        transform,
+        Visibility::Hidden,
+        RigidBody::Fixed,
+        collider,
+        ColliderDisabled,
+        ArenaPhaseElement {
+            boss_id: boss_id.to_string(),
+            phase,
+        },
+    ));
+}
+
+// Hooded merchant on a small stone slab, kept clear of the checkpoint so the two never share an interact range
+fn spawn_merchant(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    ground: Vec3,
+    rotation: f32,
+    merchant_id: &str,
+    name: &str,
+) {
+    let robe = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.18, 0.14, 0.12),
+        perceptual_roughness: 0.95,
+        ..default()
+    });
+    let slab = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.35, 0.33, 0.3),
+        perceptual_roughness: 0.9,
+        ..default()
+    });
+    
+    // Slab
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(4.0, 0.5, 4.0))),
+        MeshMaterial3d(slab),
+        Transform::from_xyz(ground.x, ground.y - 0.25, ground.z),
+        RigidBody::Fixed,
+        Collider::cuboid(2.0, 0.25, 2.0),
+    ));
+    
+    commands.spawn((
+        Mesh3d(meshes.add(Capsule3d::new(0.45, 1.0))),
+        MeshMaterial3d(robe.clone()),
+        Transform::from_xyz(ground.x, ground.y + 0.95, ground.z)
+            .with_rotation(Quat::from_rotation_y(rotation)),
+        RigidBody::Fixed,
+        Collider::capsule_y(0.5, 0.45),
+        Merchant {
+            merchant_id: merchant_id.to_string(),
+        },
+        Name::new(name.to_string()),
+    ))
+    .with_children(|parent| {
+        // Hood
+        parent.spawn((
+            Mesh3d(meshes.add(Sphere::new(0.3).mesh().ico(2).unwrap())),
+            MeshMaterial3d(robe),
+            Transform::from_xyz(0.0, 1.0, 0.0),
+        ));
+        // Lantern
+        parent.spawn((
+            PointLight {
+                intensity: 3000.0,
+                color: Color::srgb(1.0, 0.75, 0.4),
+                range: 8.0,
+                ..default()
+            },
+            Transform::from_xyz(0.5, 0.6, 0.4),
+        ));
+    });
+}
+
 fn create_material_from_metadata(
     object_meta: &ObjectMetadata,
     material_db: &MaterialDatabase,
@@ -1172,6 +1487,61 @@ fn spawn_crystalized_forest_objects(
 ) {
     let mut rng = rand::thread_rng();
     
+    // Spawn checkpoint at zone entrance
+    spawn_checkpoint(
+        commands,
+        meshes,
+        materials,
+        object_db,
+        material_db,
+        offset + Vec3::new(0.0, 0.0, 120.0),
+        WorldZone::CrystalizedForest,
+        "Crystal Grove Torch",
+    );
+    
+    // Item left a short walk past the checkpoint dais, out of the torch's interact range
+    spawn_item_pickup(
+        commands,
+        meshes,
+        materials,
+        offset + Vec3::new(8.0, 0.0, 127.0),
+        "sacred_ember",
+        1,
+        Some("pickup_crystalized_forest_01".to_string()),
+    );
+    
+    // Shard Crash Site - mini boss arena; crystal spikes erupt from the floor in phase 2
+    let arena_center = offset + Vec3::new(0.0, 0.0, -60.0);
+    spawn_boss_arena(
+        commands,
+        meshes,
+        materials,
+        arena_center,
+        25.0,
+        WorldZone::CrystalizedForest,
+        "shard_sentinel",
+        Color::srgb(0.35, 0.45, 0.55),
+    );
+    let spike_mesh = meshes.add(Cone { radius: 1.2, height: 5.0 });
+    let spike_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.4, 0.9, 1.0),
+        emissive: LinearRgba::new(0.2, 0.8, 1.0, 1.0),
+        ..default()
+    });
+    for i in 0..6 {
+        let angle = i as f32 * std::f32::consts::TAU / 6.0 + 0.3;
+        let position = arena_center + Vec3::new(angle.cos() * 14.0, 2.5, angle.sin() * 14.0);
+        spawn_arena_phase_element(
+            commands,
+            spike_mesh.clone(),
+            spike_material.clone(),
+            Transform::from_translation(position),
+            Collider::cone(2.5, 1.2),
+            "shard_sentinel",
+            1,
+        );
+    }
+    
     // Spawn crystal trees - INCREASED DENSITY
     if let Some(tree_meta) = object_db.objects.iter()
         .find(|obj| obj.object_id == "crystal_tree_01") {
@@ -1296,6 +1666,29 @@ fn spawn_sunken_city_objects(
 ) {
     let mut rng = rand::thread_rng();
     
+    // Spawn checkpoint at zone entrance
+    spawn_checkpoint(
+        commands,
+        meshes,
+        materials,
+        object_db,
+        material_db,
+        offset + Vec3::new(0.0, 0.0, 120.0),
+        WorldZone::SunkenCity,
+        "Drowned Plaza Torch",
+    );
+    
+    // Item left a short walk past the checkpoint dais, out of the torch's interact range
+    spawn_item_pickup(
+        commands,
+        meshes,
+        materials,
+        offset + Vec3::new(8.0, 0.0, 127.0),
+        "heavy_smithing_stone",
+        1,
+        Some("pickup_sunken_city_01".to_string()),
+    );
+    
     // Spawn sunken buildings - INCREASED DENSITY
     if let Some(building_meta) = object_db.objects.iter()
         .find(|obj| obj.object_id == "sunken_building_01") {
@@ -1433,6 +1826,29 @@ fn spawn_gravity_mines_objects(
 ) {
     let mut rng = rand::thread_rng();
     
+    // Spawn checkpoint at zone entrance
+    spawn_checkpoint(
+        commands,
+        meshes,
+        materials,
+        object_db,
+        material_db,
+        offset + Vec3::new(0.0, 0.0, 120.0),
+        WorldZone::GravityMines,
+        "Mine Shaft Torch",
+    );
+    
+    // Item left a short walk past the checkpoint dais, out of the torch's interact range
+    spawn_item_pickup(
+        commands,
+        meshes,
+        materials,
+        offset + Vec3::new(8.0, 0.0, 127.0),
+        "smithing_stone",
+        3,
+        Some("pickup_gravity_mines_01".to_string()),
+    );
+    
     // Spawn floating rocks - INCREASED DENSITY
     if let Some(rock_meta) = object_db.objects.iter()
         .find(|obj| obj.object_id == "floating_rock_01") {
@@ -1589,6 +2005,40 @@ fn spawn_gilded_fortress_objects(
 ) {
     let mut rng = rand::thread_rng();
     
+    // Spawn checkpoint at zone entrance
+    spawn_checkpoint(
+        commands,
+        meshes,
+        materials,
+        object_db,
+        material_db,
+        offset + Vec3::new(0.0, 0.0, 120.0),
+        WorldZone::GildedFortress,
+        "Gilded Gate Torch",
+    );
+    
+    // Item left a short walk past the checkpoint dais, out of the torch's interact range
+    spawn_item_pickup(
+        commands,
+        meshes,
+        materials,
+        offset + Vec3::new(8.0, 0.0, 127.0),
+        "heavy_smithing_stone",
+        2,
+        Some("pickup_gilded_fortress_01".to_string()),
+    );
+    
+    // Merchant beside the gate torch
+    spawn_merchant(
+        commands,
+        meshes,
+        materials,
+        offset + Vec3::new(-10.0, 0.0, 126.0),
+        2.0,
+        "fortress_merchant",
+        "Gilded Peddler",
+    );
+    
     // Spawn fortress walls - ENHANCED DESIGN
     if let Some(wall_meta) = object_db.objects.iter()
         .find(|obj| obj.object_id == "fortress_wall_segment") {
@@ -1796,6 +2246,61 @@ fn spawn_crowns_heart_objects(
 ) {
     let mut rng = rand::thread_rng();
     
+    // Spawn checkpoint at zone entrance
+    spawn_checkpoint(
+        commands,
+        meshes,
+        materials,
+        object_db,
+        material_db,
+        offset + Vec3::new(0.0, 0.0, 120.0),
+        WorldZone::CrownsHeart,
+        "Crown's Heart Torch",
+    );
+    
+    // Item left a short walk past the checkpoint dais, out of the torch's interact range
+    spawn_item_pickup(
+        commands,
+        meshes,
+        materials,
+        offset + Vec3::new(8.0, 0.0, 127.0),
+        "sacred_ember",
+        1,
+        Some("pickup_crowns_heart_01".to_string()),
+    );
+    
+    // Void Altar - final boss arena; floating platforms appear in phase 3
+    let altar_center = offset + Vec3::new(0.0, 0.0, -40.0);
+    spawn_boss_arena(
+        commands,
+        meshes,
+        materials,
+        altar_center,
+        35.0,
+        WorldZone::CrownsHeart,
+        "hollow_crown",
+        Color::srgb(0.2, 0.15, 0.25),
+    );
+    let platform_mesh = meshes.add(Cuboid::new(5.0, 0.6, 5.0));
+    let platform_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.5, 0.4, 0.7),
+        emissive: LinearRgba::new(0.3, 0.1, 0.5, 1.0),
+        ..default()
+    });
+    for i in 0..5 {
+        let angle = i as f32 * std::f32::consts::TAU / 5.0;
+        let position = altar_center + Vec3::new(angle.cos() * 20.0, 3.0 + (i % 2) as f32 * 2.0, angle.sin() * 20.0);
+        spawn_arena_phase_element(
+            commands,
+            platform_mesh.clone(),
+            platform_material.clone(),
+            Transform::from_translation(position),
+            Collider::cuboid(2.5, 0.3, 2.5),
+            "hollow_crown",
+            2,
+        );
+    }
+    
     // Spawn crown fragments - ENHANCED
     if let Some(crown_meta) = object_db.objects.iter()
         .find(|obj| obj.object_id == "crown_fragment_large") {
@@ -32,4 +32,34 @@ pub fn angle_between_vectors(a: Vec3, b: Vec3) -> f32 {
     }
     
     (dot / (mag_a * mag_b)).acos()
-}
\ No newline at end of file
+}
+
+// 캐릭터가 실제로 바라보는 수평 방향 (이동 시 로컬 +Z를 진행 방향으로 회전시킴)
+pub fn facing_direction(transform: &Transform) -> Vec3 {
+    let facing = transform.rotation * Vec3::Z;
+    Vec3::new(facing.x, 0.0, facing.z).normalize_or_zero()
+}
+
+// target 이 observer 의 정면 기준 max_angle(라디안) 이내에 있는지
+pub fn is_within_facing_angle(observer: &Transform, target: Vec3, max_angle: f32) -> bool {
+    let to_target = Vec3::new(target.x - observer.translation.x, 0.0, target.z - observer.translation.z);
+    if to_target.length_squared() < f32::EPSILON {
+        return true;
+    }
+    angle_between_vectors(facing_direction(observer), to_target) <= max_angle
+}
+
+// 움직이는 대상을 맞히는 투사체 초기 속도 - 비행 시간 동안 대상이 이동할 위치를 예측하고 중력 낙차만큼 위로 보정
+pub fn ballistic_lead_velocity(origin: Vec3, target: Vec3, target_velocity: Vec3, speed: f32, gravity: f32) -> Vec3 {
+    let mut predicted = target;
+    let mut flight_time = 0.0;
+    // 예측 위치가 바뀌면 비행 시간도 바뀌므로 몇 번 반복해서 수렴
+    for _ in 0..3 {
+        flight_time = origin.distance(predicted) / speed.max(f32::EPSILON);
+        predicted = target + target_velocity * flight_time;
+    }
+    if flight_time <= f32::EPSILON {
+        return Vec3::ZERO;
+    }
+    (predicted - origin) / flight_time + Vec3::Y * (0.5 * gravity * flight_time)
+}
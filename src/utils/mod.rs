@@ -1,3 +1,4 @@
 pub mod math;
+pub mod save;
 
 // pub use math::*;
\ No newline at end of file
@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+// saves/ 아래 JSON 저장 파일 읽기 - 파일이 없으면 새 게임 (None)
+pub fn load_save_file<T: DeserializeOwned>(path: &str, label: &str) -> Option<T> {
+    match std::fs::read_to_string(path) {
+        Ok(json) => match serde_json::from_str::<T>(&json) {
+            Ok(saved) => Some(saved),
+            Err(e) => {
+                error!("Failed to parse {} save: {}", label, e);
+                None
+            }
+        },
+        Err(_) => {
+            info!("No {} save found, starting fresh", label);
+            None
+        }
+    }
+}
+
+// 저장 폴더가 없으면 만들고 JSON 으로 기록
+pub fn write_save_file<T: Serialize>(path: &str, label: &str, data: &T) {
+    let path = std::path::Path::new(path);
+    if let Some(dir) = path.parent() {
+        if let Err(e) = std::fs::create_dir_all(dir) {
+            error!("Failed to create save directory: {}", e);
+            return;
+        }
+    }
+
+    match serde_json::to_string_pretty(data) {
+        Ok(json) => {
+            if let Err(e) = std::fs::write(path, json) {
+                error!("Failed to save {}: {}", label, e);
+            }
+        }
+        Err(e) => error!("Failed to serialize {}: {}", label, e),
+    }
+}